@@ -0,0 +1,45 @@
+//! Compares matching an Arn against many patterns with `ArnMatcher` and with a linear scan.
+//!
+//! ```text
+//! cargo bench --bench matcher
+//! ```
+
+use akton_arn::{Arn, ArnMatcher, ArnPattern};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 2_000;
+
+/// Returns the average time per call of `f`.
+fn time_per_call(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let mut patterns = Vec::new();
+    for account in 0..50 {
+        for team in 0..20 {
+            let source = format!("arn:akton:hr:acct{account}:root/team{team}/*");
+            patterns.push(ArnPattern::parse(&source).unwrap());
+        }
+    }
+    patterns.push(ArnPattern::parse("arn:akton:*:*:root/admin/**").unwrap());
+    let matcher: ArnMatcher = patterns.iter().cloned().collect();
+    let arn = Arn::try_from("arn:akton:hr:acct42:root/team7/widget").unwrap();
+
+    let linear = time_per_call(|| {
+        let arn = black_box(&arn);
+        black_box(patterns.iter().filter(|p| p.matches(arn)).count());
+    });
+    let indexed = time_per_call(|| {
+        black_box(matcher.matching(black_box(&arn)).len());
+    });
+
+    println!("patterns: {}", patterns.len());
+    println!("linear scan: {linear:?} per Arn");
+    println!("ArnMatcher: {indexed:?} per Arn");
+}
@@ -0,0 +1,69 @@
+//! Counts the heap allocations made while building, parsing, and validating a two-part Arn.
+//!
+//! Compare the default storage with the inline `smallvec` storage by running:
+//!
+//! ```text
+//! cargo bench --bench parts_alloc
+//! cargo bench --bench parts_alloc --features smallvec
+//! ```
+
+use akton_arn::{Arn, ArnParser, Part, Parts};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: usize = 10_000;
+const INPUT: &str = "arn:akton:hr:company123:root/departmentA/team1";
+
+/// Returns the average number of allocations per call of `f`.
+fn allocations_per_call(mut f: impl FnMut()) -> f64 {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    (ALLOCATIONS.load(Ordering::Relaxed) - before) as f64 / ITERATIONS as f64
+}
+
+fn main() {
+    let storage = if cfg!(feature = "smallvec") {
+        "smallvec"
+    } else {
+        "vec"
+    };
+
+    let parts = allocations_per_call(|| {
+        let parts = Parts::default()
+            .add_part(Part::new("departmentA").unwrap())
+            .add_part(Part::new("team1").unwrap());
+        black_box(parts);
+    });
+    let parsed = allocations_per_call(|| {
+        black_box(Arn::try_from(black_box(INPUT)).unwrap());
+    });
+    let validated = allocations_per_call(|| {
+        ArnParser::new(black_box(INPUT)).validate().unwrap();
+    });
+
+    println!("storage: {storage}");
+    println!("two-part Parts: {parts:.1} allocations");
+    println!("two-part Arn parse: {parsed:.1} allocations");
+    println!("two-part Arn validate: {validated:.1} allocations");
+}
@@ -0,0 +1,87 @@
+use crate::model::{Account, Arn, Category, Domain, Part, Parts, Root};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const DOMAIN: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789-";
+const ID: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+const ALPHANUMERIC: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+const PART: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789-_.";
+
+/// The most parts a generated Arn will have.
+const MAX_PARTS: usize = 6;
+
+/// Generates Arns whose components are all valid, so they display and re-parse unchanged.
+///
+/// Domains start with a lowercase letter followed by lowercase letters, digits, and `-`;
+/// categories and accounts are alphanumeric; roots look like generated ids such as `root_0a1b`;
+/// and parts also allow `-`, `_`, and `.`.
+impl<'a> Arbitrary<'a> for Arn<'static> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut domain = component(u, LETTERS, 1)?;
+        domain.push_str(&component(u, DOMAIN, 15)?);
+        let domain = Domain(Cow::Owned(domain));
+        let category = Category(Cow::Owned(component(u, ALPHANUMERIC, 16)?));
+        let account = Account(Cow::Owned(component(u, ALPHANUMERIC, 16)?));
+
+        let mut root = component(u, ID, 8)?;
+        root.push('_');
+        root.push_str(&component(u, ID, 26)?);
+        let root = Root(Cow::Owned(root));
+
+        let count = u.int_in_range(0..=MAX_PARTS)?;
+        let mut parts = Parts::default();
+        for _ in 0..count {
+            parts = parts.add_part(Part(Cow::Owned(component(u, PART, 16)?)));
+        }
+
+        Ok(Arn::new(domain, category, account, root, parts))
+    }
+}
+
+/// Returns a non-empty string of at most `max_len` characters drawn from `alphabet`.
+fn component(u: &mut Unstructured<'_>, alphabet: &[u8], max_len: usize) -> Result<String> {
+    let len = u.int_in_range(1..=max_len)?;
+    (0..len)
+        .map(|_| u.choose(alphabet).map(|&b| char::from(b)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArnParser;
+
+    /// Produces a reproducible pseudo-random byte buffer for seeding `Unstructured`.
+    fn bytes(seed: u64) -> Vec<u8> {
+        let mut state = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (0..512)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                (state >> 33) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_arbitrary_arn_round_trips() -> anyhow::Result<()> {
+        for seed in 0..2_000 {
+            let data = bytes(seed);
+            let arn = Arn::arbitrary(&mut Unstructured::new(&data))?;
+            let text = arn.to_string();
+            let parsed = ArnParser::new(text.as_str()).parse()?;
+            assert_eq!(parsed, arn, "{text}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_arbitrary_arn_from_empty_input() -> anyhow::Result<()> {
+        let arn = Arn::arbitrary(&mut Unstructured::new(&[]))?;
+        assert_eq!(ArnParser::new(arn.to_string()).parse()?, arn);
+        Ok(())
+    }
+}
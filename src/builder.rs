@@ -1,13 +1,25 @@
 use crate::errors::ArnError;
-use crate::model::{Account, Arn, Category, Domain, Part, Parts};
-use crate::traits::ArnComponent;
+use crate::model::{
+    check_part_separator, Account, Arn, Category, Domain, Part, Parts, Scheme,
+    DEFAULT_PART_SEPARATOR,
+};
+use crate::parser::{check_ascii, check_length, check_parts_count};
+use crate::traits::{ArnComponent, DynScheme, QrnScheme, SchemeKind, SchemeMarker};
 use crate::Root;
-use std::borrow::Cow;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use alloc::vec::Vec;
 
 /// A builder for constructing Arn instances using a state-driven approach with type safety.
-pub struct ArnBuilder<'a, State> {
+///
+/// The `S` parameter is the `SchemeKind` of the Arn it builds. A `SchemeMarker`, as in
+/// `ArnBuilder::<QrnScheme>::new()` through `SchemeBuilder`, fixes the scheme at compile time,
+/// and the builder builds an `Arn<'a, S>` that writes the marker's prefix without storing it.
+/// It defaults to `DynScheme`, whose scheme starts as `arn` and can be changed with
+/// `with_scheme`.
+pub struct ArnBuilder<'a, State, S = DynScheme> {
     builder: PrivateArnBuilder<'a>,
-    _marker: std::marker::PhantomData<State>,
+    _marker: core::marker::PhantomData<(State, S)>,
 }
 
 /// Implementation of `ArnBuilder` for the initial state, starting with `Domain`.
@@ -16,40 +28,311 @@ impl<'a> ArnBuilder<'a, ()> {
     pub fn new() -> ArnBuilder<'a, Domain<'a>> {
         ArnBuilder {
             builder: PrivateArnBuilder::new(),
-            _marker: std::marker::PhantomData,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Creates a new builder whose scheme is fixed by the `SchemeMarker` `S`, so the built Arn's
+    /// prefix is known from the builder's type, e.g. `ArnBuilder::for_scheme::<QrnScheme>()`.
+    ///
+    /// This is `ArnBuilder::<S>::new()` from `SchemeBuilder`, for callers without the trait in
+    /// scope. Such a builder cannot switch schemes at runtime, for either marker:
+    ///
+    /// ```compile_fail
+    /// use akton_arn::prelude::*;
+    /// use akton_arn::QrnScheme;
+    /// let builder = ArnBuilder::<QrnScheme>::new().with_scheme("arn");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use akton_arn::prelude::*;
+    /// use akton_arn::ArnScheme;
+    /// let builder = ArnBuilder::for_scheme::<ArnScheme>().with_scheme("qrn");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// let builder = akton_arn::QrnBuilder::new().with_scheme("arn");
+    /// ```
+    pub fn for_scheme<S: SchemeMarker>() -> ArnBuilder<'a, Domain<'a>, S> {
+        let mut builder = PrivateArnBuilder::new();
+        builder.scheme = Scheme(Cow::Borrowed(S::SCHEME));
+        ArnBuilder {
+            builder,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Creates a builder in the `Parts` state from an existing Arn, keeping all of its
+    /// components, parts, and scheme so more parts can be added before rebuilding.
+    pub fn from_arn(arn: Arn<'a>) -> ArnBuilder<'a, Parts<'a>> {
+        ArnBuilder {
+            builder: PrivateArnBuilder {
+                domain: Some(arn.domain),
+                category: Some(arn.category),
+                account: Some(arn.account),
+                root: Some(arn.root),
+                parts: arn.parts,
+                scheme: arn.scheme,
+                part_separator: arn.part_separator,
+                max_length: None,
+                max_parts: None,
+                ascii_only: false,
+                #[cfg(feature = "unicode")]
+                nfc: false,
+            },
+            _marker: core::marker::PhantomData,
         }
     }
 }
 
+/// Entry point for building Qrns, which starts an `ArnBuilder` with the `qrn` scheme.
+///
+/// The builder it returns is `ArnBuilder::<QrnScheme>::new()`, building an
+/// `Arn<'a, QrnScheme>`; convert the result with `into_dyn` to use it as an ordinary `Arn`.
+pub struct QrnBuilder;
+
+impl QrnBuilder {
+    /// Creates a new builder initialized to start building from the `Domain` component.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<'a>() -> ArnBuilder<'a, Domain<'a>, QrnScheme> {
+        ArnBuilder::for_scheme::<QrnScheme>()
+    }
+}
+
 /// Implementation of `ArnBuilder` for `Part` states, allowing for building the final Arn.
-impl<'a> ArnBuilder<'a, Part<'a>> {
+impl<'a, S: SchemeKind> ArnBuilder<'a, Part<'a>, S> {
     /// Finalizes the building process and constructs the Arn.
-    pub fn build(self) -> Result<Arn<'a>, ArnError> {
-        self.builder.build()
+    pub fn build(self) -> Result<Arn<'a, S>, ArnError> {
+        self.builder.build().map(Arn::into_kind)
+    }
+
+    /// Constructs the Arn like `build`, but skips every build-time check and normalization. See
+    /// `ArnBuilder::build_unchecked` on `Parts` for the list.
+    pub fn build_unchecked(self) -> Result<Arn<'a, S>, ArnError> {
+        self.builder.build_unchecked().map(Arn::into_kind)
     }
 }
 
 /// Implementation of `ArnBuilder` for handling `Parts` states.
-impl<'a> ArnBuilder<'a, Parts<'a>> {
+impl<'a, S: SchemeKind> ArnBuilder<'a, Parts<'a>, S> {
     /// Finalizes the building process and constructs the Arn when in the `Parts` state.
-    pub fn build(self) -> Result<Arn<'a>, ArnError> {
-        self.builder.build()
+    pub fn build(self) -> Result<Arn<'a, S>, ArnError> {
+        self.builder.build().map(Arn::into_kind)
+    }
+
+    /// Constructs the Arn like `build`, but skips every build-time check and normalization:
+    ///
+    /// - the maximum length set with `with_max_length`,
+    /// - the maximum number of parts set with `with_max_parts`,
+    /// - the ASCII check enabled with `with_ascii_only`,
+    /// - the NFC normalization enabled with `with_nfc`, so components are kept as given,
+    /// - the scan for parts containing a custom part separator.
+    ///
+    /// This bypasses validation and is meant for data already validated upstream, such as a
+    /// migration. Combined with `with_unchecked`, nothing stops an invalid Arn from being built,
+    /// and it may not parse back. Missing mandatory components still fail with
+    /// `ArnError::MissingPart`.
+    pub fn build_unchecked(self) -> Result<Arn<'a, S>, ArnError> {
+        self.builder.build_unchecked().map(Arn::into_kind)
+    }
+}
+
+/// Named setters for each state, equivalent to `with::<N>` without the turbofish.
+impl<'a, S> ArnBuilder<'a, Domain<'a>, S> {
+    /// Sets the domain, moving on to the category.
+    pub fn domain(
+        self,
+        domain: impl Into<Cow<'a, str>>,
+    ) -> Result<ArnBuilder<'a, Category<'a>, S>, ArnError> {
+        self.with::<Domain>(domain)
+    }
+}
+
+impl<'a, S> ArnBuilder<'a, Category<'a>, S> {
+    /// Sets the category, moving on to the account.
+    pub fn category(
+        self,
+        category: impl Into<Cow<'a, str>>,
+    ) -> Result<ArnBuilder<'a, Account<'a>, S>, ArnError> {
+        self.with::<Category>(category)
+    }
+}
+
+impl<'a, S> ArnBuilder<'a, Account<'a>, S> {
+    /// Sets the account, moving on to the root.
+    pub fn account(
+        self,
+        account: impl Into<Cow<'a, str>>,
+    ) -> Result<ArnBuilder<'a, Root<'a>, S>, ArnError> {
+        self.with::<Account>(account)
+    }
+}
+
+impl<'a, S> ArnBuilder<'a, Root<'a>, S> {
+    /// Generates the root from the given prefix, after which the Arn can be built.
+    pub fn root(
+        self,
+        root: impl Into<Cow<'a, str>>,
+    ) -> Result<ArnBuilder<'a, Part<'a>, S>, ArnError> {
+        self.with::<Root>(root)
+    }
+}
+
+impl<'a, S: SchemeKind> ArnBuilder<'a, Part<'a>, S> {
+    /// Appends the first part after the root.
+    pub fn part(
+        self,
+        part: impl Into<Cow<'a, str>>,
+    ) -> Result<ArnBuilder<'a, Parts<'a>, S>, ArnError> {
+        self.with::<Part>(part)
+    }
+}
+
+impl<'a, S> ArnBuilder<'a, Parts<'a>, S> {
+    /// Appends another part.
+    pub fn part(
+        self,
+        part: impl Into<Cow<'a, str>>,
+    ) -> Result<ArnBuilder<'a, Parts<'a>, S>, ArnError> {
+        self.with::<Part>(part)
+    }
+}
+
+/// Bulk part appending for the states that accept parts.
+impl<'a, S, T: ArnComponent<'a, NextState = Parts<'a>>> ArnBuilder<'a, T, S> {
+    /// Validates and appends each segment in order, reserving room for them up front from the
+    /// iterator's size hint.
+    ///
+    /// Stops at the first invalid segment and returns `ArnError::InvalidPart` with its index
+    /// within `parts` and its value.
+    pub fn with_parts<P>(
+        self,
+        parts: impl IntoIterator<Item = P>,
+    ) -> Result<ArnBuilder<'a, Parts<'a>, S>, ArnError>
+    where
+        P: Into<Cow<'a, str>>,
+    {
+        let mut builder = self.builder;
+        let parts = parts.into_iter();
+        builder.parts.0.reserve(parts.size_hint().0);
+        for (index, part) in parts.enumerate() {
+            let part = part.into();
+            let valid = Part::new(part.clone()).map_err(|_| ArnError::InvalidPart {
+                index,
+                value: part.into_owned(),
+            })?;
+            builder.parts = builder.parts.add_part(valid);
+        }
+        Ok(ArnBuilder {
+            builder,
+            _marker: core::marker::PhantomData,
+        })
+    }
+}
+
+/// Options that apply to the builder in any state.
+impl<'a, State, S> ArnBuilder<'a, State, S> {
+    /// Limits the built Arn to at most `max` bytes when rendered. Building is unlimited by default.
+    pub fn with_max_length(mut self, max: usize) -> Self {
+        self.builder.max_length = Some(max);
+        self
+    }
+
+    /// Limits the built Arn to at most `max` parts after the root. Building is unlimited by
+    /// default, and fails with `ArnError::TooManyParts` when more parts were added.
+    pub fn with_max_parts(mut self, max: usize) -> Self {
+        self.builder.max_parts = Some(max);
+        self
+    }
+
+    /// Rejects a built Arn containing any non-ASCII character with `ArnError::NonAscii` at its
+    /// byte offset in the rendered Arn. Off by default.
+    pub fn with_ascii_only(mut self) -> Self {
+        self.builder.ascii_only = true;
+        self
+    }
+
+    /// Normalizes every component of the built Arn to Unicode NFC, as `Arn::into_nfc` does, so
+    /// visually identical Arns compare equal. Off by default.
+    #[cfg(feature = "unicode")]
+    pub fn with_nfc(mut self) -> Self {
+        self.builder.nfc = true;
+        self
+    }
+
+    /// Sets the separator placed between the root and each part, such as `.`. Defaults to `/`.
+    ///
+    /// Choosing `:`, the component separator, or a character that may appear in a root or part,
+    /// such as `_`, `-`, alphanumerics, whitespace, and `%`, fails with `ArnError::InvalidValue`.
+    /// Building fails with `ArnError::InvalidPart` if a part contains the chosen separator.
+    pub fn with_part_separator(mut self, separator: char) -> Result<Self, ArnError> {
+        self.builder.part_separator = check_part_separator(separator)?;
+        Ok(self)
+    }
+
+    /// Checks that every mandatory component has been set, without consuming the builder.
+    ///
+    /// Returns `ArnError::MissingPart` naming each unset component in order, e.g. `account, root`,
+    /// so progress can be reported mid-construction. Parts are optional and never reported.
+    pub fn validate(&self) -> Result<(), ArnError> {
+        let builder = &self.builder;
+        let missing: Vec<&str> = [
+            ("domain", builder.domain.is_none()),
+            ("category", builder.category.is_none()),
+            ("account", builder.account.is_none()),
+            ("root", builder.root.is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(name, unset)| unset.then_some(name))
+        .collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(ArnError::MissingPart(missing.join(", ")))
+        }
+    }
+}
+
+/// Runtime scheme selection, available only with the default `DynScheme` kind since a
+/// `SchemeMarker` fixes the scheme at compile time.
+impl<'a, State> ArnBuilder<'a, State, DynScheme> {
+    /// Sets the scheme the built Arn starts with, such as `qrn`. Defaults to `arn`.
+    pub fn with_scheme(mut self, scheme: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
+        self.builder.scheme = Scheme::new(scheme)?;
+        Ok(self)
     }
 }
 
 /// Generic implementation of `ArnBuilder` for all states that can transition to another state.
-impl<'a, T: ArnComponent<'a>> ArnBuilder<'a, T> {
+impl<'a, S, T: ArnComponent<'a>> ArnBuilder<'a, T, S> {
     /// Adds a new part to the Arn, transitioning to the next appropriate state.
     pub fn with<N>(
         self,
         part: impl Into<Cow<'a, str>>,
-    ) -> Result<ArnBuilder<'a, N::NextState>, ArnError>
+    ) -> Result<ArnBuilder<'a, N::NextState, S>, ArnError>
     where
         N: ArnComponent<'a, NextState = T::NextState>,
     {
         Ok(ArnBuilder {
             builder: self.builder.add_part(N::prefix(), part.into())?,
-            _marker: std::marker::PhantomData,
+            _marker: core::marker::PhantomData,
+        })
+    }
+
+    /// Adds a component like `with`, but stores the value verbatim without validating it.
+    ///
+    /// This bypasses validation and is meant for data already validated upstream. Invalid values
+    /// produce an Arn that may not parse back.
+    pub fn with_unchecked<N>(
+        self,
+        part: impl Into<Cow<'a, str>>,
+    ) -> Result<ArnBuilder<'a, N::NextState, S>, ArnError>
+    where
+        N: ArnComponent<'a, NextState = T::NextState>,
+    {
+        Ok(ArnBuilder {
+            builder: self.builder.add_part_unchecked(N::prefix(), part.into())?,
+            _marker: core::marker::PhantomData,
         })
     }
 }
@@ -61,6 +344,13 @@ struct PrivateArnBuilder<'a> {
     account: Option<Account<'a>>,
     root: Option<Root<'a>>,
     parts: Parts<'a>,
+    scheme: Scheme<'a>,
+    part_separator: char,
+    max_length: Option<usize>,
+    max_parts: Option<usize>,
+    ascii_only: bool,
+    #[cfg(feature = "unicode")]
+    nfc: bool,
 }
 
 impl<'a> PrivateArnBuilder<'a> {
@@ -72,9 +362,21 @@ impl<'a> PrivateArnBuilder<'a> {
             account: None,
             root: None,
             parts: Parts::new(Vec::new()),
+            scheme: Scheme::default(),
+            part_separator: DEFAULT_PART_SEPARATOR,
+            max_length: None,
+            max_parts: None,
+            ascii_only: false,
+            #[cfg(feature = "unicode")]
+            nfc: false,
         }
     }
 
+    /// Validates and stores a component, choosing the slot from the component's prefix.
+    ///
+    /// The prefix only routes the value: a part arrives with `""` from the `Part` component and
+    /// with `":"` from `Parts`, and both are stored alike. Prefixes are never rendered, since
+    /// `Display` joins the root and each part with the part separator alone.
     fn add_part(mut self, prefix: &'static str, part: Cow<'a, str>) -> Result<Self, ArnError> {
         match prefix {
             p if p == Domain::prefix() => {
@@ -82,9 +384,9 @@ impl<'a> PrivateArnBuilder<'a> {
             }
             "" => {
                 if self.domain.is_some() && self.category.is_none() {
-                    self.category = Some(Category::new(part));
+                    self.category = Some(Category::new(part)?);
                 } else if self.category.is_some() && self.account.is_none() {
-                    self.account = Some(Account::new(part));
+                    self.account = Some(Account::new(part)?);
                 } else if self.account.is_some() && self.root.is_none() {
                     self.root = Some(Root::new(part)?);
                 } else {
@@ -100,8 +402,60 @@ impl<'a> PrivateArnBuilder<'a> {
         Ok(self)
     }
 
+    /// Stores a component verbatim, mirroring `add_part` without any validation.
+    fn add_part_unchecked(
+        mut self,
+        prefix: &'static str,
+        part: Cow<'a, str>,
+    ) -> Result<Self, ArnError> {
+        match prefix {
+            p if p == Domain::prefix() => self.domain = Some(Domain(part)),
+            "" if self.domain.is_some() && self.category.is_none() => {
+                self.category = Some(Category(part))
+            }
+            "" if self.category.is_some() && self.account.is_none() => {
+                self.account = Some(Account(part))
+            }
+            "" if self.account.is_some() && self.root.is_none() => self.root = Some(Root(part)),
+            "" | ":" => self.parts = self.parts.add_part(Part(part)),
+            _ => return Err(ArnError::InvalidPrefix(prefix.to_string())),
+        }
+        Ok(self)
+    }
+
     /// Finalizes and builds the Arn.
     fn build(self) -> Result<Arn<'a>, ArnError> {
+        let max_length = self.max_length;
+        let ascii_only = self.ascii_only;
+        check_parts_count(self.parts.len(), self.max_parts)?;
+        #[cfg(feature = "unicode")]
+        let nfc = self.nfc;
+        let arn = self.build_unchecked()?;
+        #[cfg(feature = "unicode")]
+        let arn = if nfc { arn.into_nfc() } else { arn };
+
+        // Part::new already rejects `/`; a custom separator must be checked once it is known.
+        let separator = arn.part_separator;
+        let mut parts = arn.parts.0.iter().enumerate();
+        if let Some((index, part)) = parts.find(|(_, part)| part.as_str().contains(separator)) {
+            return Err(ArnError::InvalidPart {
+                index,
+                value: part.as_str().to_string(),
+            });
+        }
+
+        if max_length.is_some() || ascii_only {
+            let rendered = arn.to_string();
+            check_length(rendered.len(), max_length)?;
+            if ascii_only {
+                check_ascii(&rendered)?;
+            }
+        }
+        Ok(arn)
+    }
+
+    /// Assembles the Arn from the components set so far, requiring every mandatory one.
+    fn build_unchecked(self) -> Result<Arn<'a>, ArnError> {
         let domain = self
             .domain
             .ok_or(ArnError::MissingPart("domain".to_string()))?;
@@ -113,7 +467,10 @@ impl<'a> PrivateArnBuilder<'a> {
             .ok_or(ArnError::MissingPart("account".to_string()))?;
         let root = self.root.ok_or(ArnError::MissingPart("root".to_string()))?;
 
-        Ok(Arn::new(domain, category, account, root, self.parts))
+        let mut arn = Arn::new(domain, category, account, root, self.parts);
+        arn.scheme = self.scheme.into_static();
+        arn.part_separator = self.part_separator;
+        Ok(arn)
     }
 }
 
@@ -121,8 +478,11 @@ impl<'a> PrivateArnBuilder<'a> {
 mod tests {
     use super::*;
     use crate::errors::ArnError;
+    #[cfg(feature = "std")]
     use crate::tests::init_tracing;
     use crate::{ArnBuilder, ArnParser};
+    use alloc::string::String;
+    use alloc::{format, vec};
 
     #[test]
     fn test() -> anyhow::Result<()> {
@@ -163,6 +523,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_arn_builder_with_default_parts() -> anyhow::Result<(), ArnError> {
         init_tracing();
         let arn = Arn::default();
@@ -174,6 +535,486 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_arn_builder_rejects_invalid_account() -> anyhow::Result<()> {
+        let builder = ArnBuilder::new()
+            .with::<Domain>("custom")?
+            .with::<Category>("service")?;
+        assert!(matches!(
+            builder.with::<Account>("acct/123"),
+            Err(ArnError::InvalidValue("Account", _))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_round_trips() -> anyhow::Result<()> {
+        let accounts = ["company123", "acct-1", "", "acct:1", "acct/1", "a.b_c"];
+        let mut built = 0;
+        for account in accounts {
+            let arn = ArnBuilder::new()
+                .with::<Domain>("custom")?
+                .with::<Category>("service")?
+                .with::<Account>(account)
+                .and_then(|builder| builder.with::<Root>("root"))
+                .and_then(|builder| builder.with::<Part>("resource"))
+                .and_then(|builder| builder.build());
+            if let Ok(arn) = arn {
+                assert_eq!(ArnParser::new(arn.to_string()).parse()?, arn);
+                built += 1;
+            }
+        }
+        assert_eq!(built, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_rejects_part_with_separator() -> anyhow::Result<()> {
+        let builder = ArnBuilder::new()
+            .with::<Domain>("custom")?
+            .with::<Category>("service")?
+            .with::<Account>("account123")?
+            .with::<Root>("root")?;
+        assert!(matches!(
+            builder.with::<Part>("team/extra"),
+            Err(ArnError::InvalidPartFormat)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_parts_round_trip() -> anyhow::Result<()> {
+        let arn = ArnBuilder::new()
+            .with::<Domain>("custom")?
+            .with::<Category>("service")?
+            .with::<Account>("account123")?
+            .with::<Root>("root")?
+            .with::<Part>("team")?
+            .with::<Part>("extra")?
+            .build()?;
+        let parsed = ArnParser::new(arn.to_string()).parse()?;
+        assert_eq!(parsed.parts, arn.parts);
+        assert_eq!(parsed.parts.iter().count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_max_length() -> anyhow::Result<()> {
+        let build = |max: usize| {
+            ArnBuilder::new()
+                .with_max_length(max)
+                .with::<Domain>("custom")?
+                .with::<Category>("service")?
+                .with::<Account>("account123")?
+                .with::<Root>("root")?
+                .with::<Part>("resource")?
+                .build()
+        };
+        let length = build(usize::MAX)?.to_string().len();
+        assert!(build(length).is_ok());
+        assert_eq!(
+            build(length - 1),
+            Err(ArnError::TooLong {
+                length,
+                max: length - 1
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_max_parts() -> anyhow::Result<()> {
+        let build = |max: usize| {
+            ArnBuilder::new()
+                .with_max_parts(max)
+                .with::<Domain>("custom")?
+                .with::<Category>("service")?
+                .with::<Account>("account123")?
+                .with::<Root>("root")?
+                .with_parts(["a", "b"])?
+                .build()
+        };
+        assert!(build(2).is_ok());
+        assert_eq!(build(1), Err(ArnError::TooManyParts { count: 2, max: 1 }));
+        Ok(())
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_arn_builder_with_nfc() -> anyhow::Result<()> {
+        let build = |category: &'static str| {
+            ArnBuilder::new()
+                .with_nfc()
+                .with::<Domain>("akton")?
+                .with::<Category>(category)?
+                .with::<Account>("acct")?
+                .with_unchecked::<Root>("root")?
+                .build()
+        };
+        assert_eq!(build("cafe\u{301}")?, build("caf\u{e9}")?);
+        assert_eq!(build("cafe\u{301}")?.category(), "caf\u{e9}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_ascii_only() -> anyhow::Result<()> {
+        let build = |ascii_only: bool| {
+            let builder = ArnBuilder::new();
+            let builder = if ascii_only {
+                builder.with_ascii_only()
+            } else {
+                builder
+            };
+            builder
+                .with::<Domain>("akton")?
+                .with::<Category>("hr")?
+                .with::<Account>("acct")?
+                .with_unchecked::<Root>("root")?
+                .with::<Part>("team\u{1F680}")?
+                .build()
+        };
+        assert_eq!(
+            build(false)?.to_string(),
+            "arn:akton:hr:acct:root/team\u{1F680}"
+        );
+        assert_eq!(build(true), Err(ArnError::NonAscii { position: 27 }));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_parts_round_trip_by_count() -> anyhow::Result<()> {
+        let names = ["teamA", "team1", "member"];
+        for count in 1..=names.len() {
+            // The first part goes through the `Part` component and the rest through `Parts`,
+            // exercising both internal prefixes.
+            let mut builder = ArnBuilder::new()
+                .with::<Domain>("akton")?
+                .with::<Category>("hr")?
+                .with::<Account>("acct")?
+                .with_unchecked::<Root>("root")?
+                .with::<Part>(names[0])?;
+            for name in &names[1..count] {
+                builder = builder.with::<Parts>(*name)?;
+            }
+            let arn = builder.build()?;
+
+            let text = arn.to_string();
+            assert_eq!(
+                text,
+                format!("arn:akton:hr:acct:root/{}", names[..count].join("/"))
+            );
+            let parsed = ArnParser::new(text.as_str()).parse()?;
+            assert_eq!(parsed.parts(), arn.parts());
+            assert_eq!(parsed.parts().len(), count);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_with_parts_reserves_capacity() -> anyhow::Result<()> {
+        let names: Vec<String> = (0..100).map(|i| format!("segment{i}")).collect();
+        let builder = ArnBuilder::new()
+            .with::<Domain>("akton")?
+            .with::<Category>("hr")?
+            .with::<Account>("acct")?
+            .with_unchecked::<Root>("root")?
+            .with_parts(names.iter().map(String::as_str))?;
+        assert!(builder.builder.parts.0.capacity() >= 100);
+        let arn = builder.build()?;
+        assert_eq!(arn.parts().len(), 100);
+        assert_eq!(arn.root_path(), format!("root/{}", names.join("/")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_named_setters() -> anyhow::Result<()> {
+        let arn = ArnBuilder::new()
+            .domain("akton-internal")?
+            .category("hr")?
+            .account("company123")?
+            .root("root")?
+            .part("departmentA")?
+            .part("team1")?
+            .build()?;
+        assert_eq!(
+            arn.to_string(),
+            "arn:akton-internal:hr:company123:root/departmentA/team1"
+        );
+
+        let parsed = ArnParser::new(arn.to_string()).parse()?;
+        assert_eq!(parsed, arn);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_named_setters_without_parts() -> anyhow::Result<()> {
+        let arn = ArnBuilder::new()
+            .domain("custom")?
+            .category("service")?
+            .account("account123")?
+            .root("resource")?
+            .build()?;
+        assert!(arn.parts.is_empty());
+        assert!(ArnBuilder::new().domain("Invalid Domain").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_rejects_separator_in_category() -> anyhow::Result<()> {
+        let result = ArnBuilder::new().domain("akton")?.category("h:r");
+        assert_eq!(
+            result.err(),
+            Some(ArnError::InvalidValue("Category", "h:r".to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_with_parts() -> anyhow::Result<()> {
+        let segments = vec![
+            "departmentA".to_string(),
+            "team1".to_string(),
+            "member1".to_string(),
+        ];
+        let arn = ArnBuilder::new()
+            .domain("akton-internal")?
+            .category("hr")?
+            .account("company123")?
+            .root("root")?
+            .with_parts(segments)?
+            .build()?;
+        assert_eq!(arn.parts.to_string(), "departmentA/team1/member1");
+
+        let arn = arn.to_builder().with_parts(["widget"])?.build()?;
+        assert_eq!(arn.parts.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_with_parts_names_invalid_part() -> anyhow::Result<()> {
+        let result = ArnBuilder::new()
+            .domain("akton-internal")?
+            .category("hr")?
+            .account("company123")?
+            .root("root")?
+            .with_parts(["departmentA", "team:1", ""]);
+        assert_eq!(
+            result.err(),
+            Some(ArnError::InvalidPart {
+                index: 1,
+                value: "team:1".to_string()
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_from_arn() -> anyhow::Result<()> {
+        let input = "arn:akton-internal:hr:company123:root/departmentA";
+        let arn = ArnParser::new(input).parse()?;
+
+        let rebuilt = ArnBuilder::from_arn(arn.clone())
+            .with::<Part>("team1")?
+            .build()?;
+        assert_eq!(rebuilt.to_string(), format!("{input}/team1"));
+        assert_eq!(rebuilt.root, arn.root);
+
+        let unchanged = arn.to_builder().build()?;
+        assert_eq!(unchanged, arn);
+        Ok(())
+    }
+
+    #[test]
+    fn test_qrn_builder() -> anyhow::Result<()> {
+        let qrn: Arn<QrnScheme> = QrnBuilder::new()
+            .domain("quasar")?
+            .category("service")?
+            .account("account123")?
+            .root("root")?
+            .part("resource")?
+            .build()?;
+        assert_eq!(qrn.scheme(), "qrn");
+        assert_eq!(
+            qrn.to_string(),
+            "qrn:quasar:service:account123:root/resource"
+        );
+
+        let parsed = crate::QrnParser::new(qrn.to_string()).parse()?;
+        assert_eq!(parsed, crate::Qrn::from(qrn));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_with_scheme() -> anyhow::Result<()> {
+        let arn = ArnBuilder::new()
+            .with_scheme("qrn")?
+            .with::<Domain>("quasar")?
+            .with::<Category>("service")?
+            .with::<Account>("account123")?
+            .with::<Root>("root")?
+            .with::<Part>("resource")?
+            .build()?;
+        let text = arn.to_string();
+        assert_eq!(text, "qrn:quasar:service:account123:root/resource");
+
+        let parsed = ArnParser::new(text.as_str()).with_scheme("qrn")?.parse()?;
+        assert_eq!(parsed, arn);
+        assert!(ArnBuilder::new().with_scheme("Q:RN").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_with_part_separator() -> anyhow::Result<()> {
+        let arn = ArnBuilder::new()
+            .with_part_separator('.')?
+            .with::<Domain>("quasar")?
+            .with::<Category>("service")?
+            .with::<Account>("account123")?
+            .with::<Root>("root")?
+            .with::<Part>("teamA")?
+            .with::<Part>("member1")?
+            .build()?;
+        let text = arn.to_string();
+        assert!(text.ends_with(".teamA.member1"), "{text}");
+        assert!(!text.contains('/'));
+
+        let parsed = ArnParser::new(text.as_str())
+            .with_part_separator('.')?
+            .parse()?;
+        assert_eq!(parsed, arn);
+        assert_eq!(parsed.to_string(), text);
+
+        assert_eq!(
+            ArnBuilder::new().with_part_separator(':').err(),
+            Some(ArnError::InvalidValue("Part separator", ":".to_string()))
+        );
+        let dotted_part = ArnBuilder::new()
+            .with_part_separator('.')?
+            .with::<Domain>("quasar")?
+            .with::<Category>("service")?
+            .with::<Account>("account123")?
+            .with::<Root>("root")?
+            .with::<Part>("v1.2")?
+            .build();
+        assert_eq!(
+            dotted_part,
+            Err(ArnError::InvalidPart {
+                index: 0,
+                value: "v1.2".to_string()
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_build_unchecked() -> anyhow::Result<()> {
+        let arn = ArnBuilder::new()
+            .with::<Domain>("quasar")?
+            .with::<Category>("service")?
+            .with::<Account>("account123")?
+            .with::<Root>("root")?
+            .with::<Part>("teamA")?
+            .build()?;
+        assert_eq!(arn.to_builder().build_unchecked()?, arn);
+
+        let unchecked = ArnBuilder::new()
+            .with_unchecked::<Domain>("quasar")?
+            .with_unchecked::<Category>("service")?
+            .with_unchecked::<Account>("account123")?
+            .with_unchecked::<Root>(arn.root().to_string())?
+            .with_unchecked::<Part>("teamA")?
+            .build_unchecked()?;
+        assert_eq!(unchecked, arn);
+        assert_eq!(unchecked.to_string(), arn.to_string());
+
+        let missing = ArnBuilder::new()
+            .with_unchecked::<Domain>("quasar")?
+            .with_unchecked::<Category>("service")?
+            .with_unchecked::<Account>("account123")?;
+        assert_eq!(
+            missing.builder.build_unchecked(),
+            Err(ArnError::MissingPart("root".to_string()))
+        );
+
+        let limited = || -> Result<_, ArnError> {
+            ArnBuilder::new()
+                .with_max_length(10)
+                .with_max_parts(1)
+                .with_ascii_only()
+                .with::<Domain>("quasar")?
+                .with::<Category>("service")?
+                .with::<Account>("account123")?
+                .with::<Root>("root")?
+                .with::<Part>("caf\u{e9}")?
+                .with::<Part>("teamB")
+        };
+        assert!(limited()?.build().is_err());
+        assert_eq!(limited()?.build_unchecked()?.parts().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_validate_each_stage() -> anyhow::Result<()> {
+        let missing = |names: &str| Err(ArnError::MissingPart(names.to_string()));
+
+        let builder = ArnBuilder::new();
+        assert_eq!(
+            builder.validate(),
+            missing("domain, category, account, root")
+        );
+        let builder = builder.with::<Domain>("quasar")?;
+        assert_eq!(builder.validate(), missing("category, account, root"));
+        let builder = builder.with::<Category>("service")?;
+        assert_eq!(builder.validate(), missing("account, root"));
+        let builder = builder.with::<Account>("account123")?;
+        assert_eq!(builder.validate(), missing("root"));
+        let builder = builder.with::<Root>("root")?;
+        assert_eq!(builder.validate(), Ok(()));
+        let builder = builder.with::<Part>("teamA")?;
+        assert_eq!(builder.validate(), Ok(()));
+        assert!(builder.build().is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_builder_scheme_markers() -> anyhow::Result<()> {
+        use crate::{ArnScheme, SchemeBuilder};
+
+        fn build<'a, S: SchemeKind>(
+            builder: ArnBuilder<'a, Domain<'a>, S>,
+        ) -> anyhow::Result<Arn<'a, S>> {
+            Ok(builder
+                .with::<Domain>("quasar")?
+                .with::<Category>("service")?
+                .with::<Account>("account123")?
+                .with::<Root>("root")?
+                .with::<Part>("resource")?
+                .build()?)
+        }
+
+        let qrn = build(ArnBuilder::<QrnScheme>::new())?;
+        assert_eq!(qrn.scheme(), QrnScheme::SCHEME);
+        assert_eq!(
+            qrn.to_string(),
+            "qrn:quasar:service:account123:root/resource"
+        );
+        let arn = build(ArnBuilder::<ArnScheme>::new())?;
+        assert!(arn.to_string().starts_with("arn:quasar:"));
+        let aliased = build(ArnBuilder::for_scheme::<QrnScheme>())?;
+        assert_eq!(aliased.scheme(), QrnScheme::SCHEME);
+        assert_eq!(build(QrnBuilder::new())?, qrn);
+
+        // A marker's scheme lives only in the type, while the default kind stores its own
+        let dynamic = build(ArnBuilder::new())?;
+        assert_eq!(dynamic.scheme(), ArnScheme::SCHEME);
+        assert!(core::mem::size_of::<Arn<QrnScheme>>() < core::mem::size_of::<Arn>());
+        let converted = qrn.into_dyn();
+        assert_eq!(converted.scheme(), QrnScheme::SCHEME);
+        assert_eq!(converted.to_string(), aliased.to_string());
+        assert_eq!(arn.into_dyn(), dynamic);
+        Ok(())
+    }
+
     #[test]
     fn test_arn_builder_with_owned_strings() -> anyhow::Result<(), ArnError> {
         let arn = ArnBuilder::new()
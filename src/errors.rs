@@ -1,6 +1,11 @@
-use std::convert::Infallible;
+use alloc::string::String;
+use core::convert::Infallible;
 
 // Merged ArnBuilderError and ArnParseError into ArnError
+/// Errors produced while building, parsing, or validating Arns.
+///
+/// Each variant has a human-readable `Display` message, and `ArnError` implements
+/// `std::error::Error` with no `source`, so it works with `?` and `anyhow`.
 #[derive(Debug, thiserror::Error, PartialEq)]
 pub enum ArnError {
     #[error("Failed to parse {0}: {1}")]
@@ -25,18 +30,308 @@ pub enum ArnError {
     #[error("ARN has invalid format")]
     InvalidFormat,
 
+    #[error("Invalid character '{found}' at position {position}")]
+    InvalidCharacter { found: char, position: usize },
+
+    #[error("Missing ':' separator at position {position}")]
+    MissingSeparator { position: usize },
+
+    #[error("Empty part segment at position {position}")]
+    EmptyPart { position: usize },
+
+    #[error("Unexpected whitespace at position {position}")]
+    UnexpectedWhitespace { position: usize },
+
+    #[error("Invalid UTF-8 at byte {position}")]
+    InvalidUtf8 { position: usize },
+
+    #[error("{0} has invalid value: {1}")]
+    InvalidValue(&'static str, String),
+
+    #[error("Part {index} has invalid value '{value}'")]
+    InvalidPart { index: usize, value: String },
+
+    #[error("Part index {index} is out of bounds for {len} parts")]
+    PartIndexOutOfBounds { index: usize, len: usize },
+
+    #[error("ARN is {length} bytes long, exceeding the maximum of {max}")]
+    TooLong { length: usize, max: usize },
+
+    #[error("ARN has {count} parts, exceeding the maximum of {max}")]
+    TooManyParts { count: usize, max: usize },
+
+    #[error("Non-ASCII character at byte {position}")]
+    NonAscii { position: usize },
+
+    #[error("Template placeholder '{0}' has no value")]
+    MissingPlaceholder(String),
+
     // Converted the Infallible implementation to ArnError
     #[error("Infallible error")]
     InfallibleError,
 }
 
+/// The kind of an `ArnError`, without its data, for matching on failures programmatically.
+///
+/// Each `ArnError` variant has exactly one kind of the same name, and kinds stay stable when
+/// error messages change. New kinds may be added, so matches need a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArnErrorKind {
+    ParseFailure,
+    IllegalPartFormat,
+    InvalidPrefix,
+    UnexpectedPart,
+    InvalidPartFormat,
+    IdGenerationFailure,
+    MissingPart,
+    InvalidFormat,
+    InvalidCharacter,
+    MissingSeparator,
+    EmptyPart,
+    UnexpectedWhitespace,
+    InvalidUtf8,
+    InvalidValue,
+    InvalidPart,
+    PartIndexOutOfBounds,
+    TooLong,
+    TooManyParts,
+    NonAscii,
+    MissingPlaceholder,
+    InfallibleError,
+}
+
+impl ArnError {
+    /// Returns the kind of this error, e.g. to map it onto a status code.
+    pub fn kind(&self) -> ArnErrorKind {
+        match self {
+            ArnError::ParseFailure(..) => ArnErrorKind::ParseFailure,
+            ArnError::IllegalPartFormat => ArnErrorKind::IllegalPartFormat,
+            ArnError::InvalidPrefix(_) => ArnErrorKind::InvalidPrefix,
+            ArnError::UnexpectedPart(_) => ArnErrorKind::UnexpectedPart,
+            ArnError::InvalidPartFormat => ArnErrorKind::InvalidPartFormat,
+            ArnError::IdGenerationFailure(_) => ArnErrorKind::IdGenerationFailure,
+            ArnError::MissingPart(_) => ArnErrorKind::MissingPart,
+            ArnError::InvalidFormat => ArnErrorKind::InvalidFormat,
+            ArnError::InvalidCharacter { .. } => ArnErrorKind::InvalidCharacter,
+            ArnError::MissingSeparator { .. } => ArnErrorKind::MissingSeparator,
+            ArnError::EmptyPart { .. } => ArnErrorKind::EmptyPart,
+            ArnError::UnexpectedWhitespace { .. } => ArnErrorKind::UnexpectedWhitespace,
+            ArnError::InvalidUtf8 { .. } => ArnErrorKind::InvalidUtf8,
+            ArnError::InvalidValue(..) => ArnErrorKind::InvalidValue,
+            ArnError::InvalidPart { .. } => ArnErrorKind::InvalidPart,
+            ArnError::PartIndexOutOfBounds { .. } => ArnErrorKind::PartIndexOutOfBounds,
+            ArnError::TooLong { .. } => ArnErrorKind::TooLong,
+            ArnError::TooManyParts { .. } => ArnErrorKind::TooManyParts,
+            ArnError::NonAscii { .. } => ArnErrorKind::NonAscii,
+            ArnError::MissingPlaceholder(_) => ArnErrorKind::MissingPlaceholder,
+            ArnError::InfallibleError => ArnErrorKind::InfallibleError,
+        }
+    }
+}
+
 impl From<Infallible> for ArnError {
     fn from(_: Infallible) -> Self {
         ArnError::InfallibleError
     }
 }
+#[cfg(feature = "std")]
 impl From<type_safe_id::Error> for ArnError {
     fn from(e: type_safe_id::Error) -> Self {
         ArnError::IdGenerationFailure(e.to_string())
     }
 }
+
+/// Maps to `ErrorKind::InvalidData`, carrying the `ArnError` as the inner error, so `?` works in
+/// functions returning `std::io::Result`.
+#[cfg(feature = "std")]
+impl From<ArnError> for std::io::Error {
+    fn from(e: ArnError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    #[cfg(feature = "std")]
+    use std::error::Error;
+
+    #[test]
+    fn test_error_display_messages() {
+        assert_eq!(
+            ArnError::MissingPart("domain".to_string()).to_string(),
+            "Builder Error - Missing required part: domain"
+        );
+        assert_eq!(
+            ArnError::InvalidPrefix("urn:".to_string()).to_string(),
+            "Builder Error - Invalid prefix: urn:"
+        );
+        assert_eq!(
+            ArnError::InvalidValue("Domain", "Akton".to_string()).to_string(),
+            "Domain has invalid value: Akton"
+        );
+        assert_eq!(
+            ArnError::InvalidCharacter {
+                found: ':',
+                position: 12
+            }
+            .to_string(),
+            "Invalid character ':' at position 12"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_error_is_std_error() {
+        let err: Box<dyn Error> = Box::new(ArnError::InvalidFormat);
+        assert_eq!(err.to_string(), "ARN has invalid format");
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_error_into_io_error() {
+        let errors = [
+            ArnError::ParseFailure("Domain", "cannot be empty".to_string()),
+            ArnError::IllegalPartFormat,
+            ArnError::InvalidPrefix("urn:".to_string()),
+            ArnError::UnexpectedPart("extra".to_string()),
+            ArnError::InvalidPartFormat,
+            ArnError::IdGenerationFailure("clock".to_string()),
+            ArnError::MissingPart("domain".to_string()),
+            ArnError::InvalidFormat,
+            ArnError::InvalidCharacter {
+                found: ':',
+                position: 12,
+            },
+            ArnError::MissingSeparator { position: 3 },
+            ArnError::EmptyPart { position: 23 },
+            ArnError::UnexpectedWhitespace { position: 0 },
+            ArnError::InvalidValue("Domain", "Akton".to_string()),
+            ArnError::InvalidPart {
+                index: 1,
+                value: "a/b".to_string(),
+            },
+            ArnError::TooLong {
+                length: 300,
+                max: 256,
+            },
+            ArnError::TooManyParts { count: 3, max: 2 },
+            ArnError::NonAscii { position: 35 },
+            ArnError::MissingPlaceholder("team".to_string()),
+            ArnError::InvalidUtf8 { position: 4 },
+            ArnError::PartIndexOutOfBounds { index: 3, len: 2 },
+            ArnError::InfallibleError,
+        ];
+        for error in errors {
+            let message = error.to_string();
+            let io: std::io::Error = error.into();
+            assert_eq!(io.kind(), std::io::ErrorKind::InvalidData);
+            assert_eq!(io.to_string(), message);
+            assert!(io.get_ref().is_some_and(|inner| inner.is::<ArnError>()));
+        }
+
+        fn read_arn(input: &str) -> std::io::Result<crate::Arn<'_>> {
+            Ok(crate::Arn::try_from(input)?)
+        }
+        assert!(read_arn("arn:akton:hr:acct:root").is_ok());
+        assert!(read_arn("invalid").is_err());
+    }
+
+    #[test]
+    fn test_error_kinds() {
+        let cases = [
+            (
+                ArnError::ParseFailure("Domain", "cannot be empty".to_string()),
+                ArnErrorKind::ParseFailure,
+            ),
+            (ArnError::IllegalPartFormat, ArnErrorKind::IllegalPartFormat),
+            (
+                ArnError::InvalidPrefix("urn:".to_string()),
+                ArnErrorKind::InvalidPrefix,
+            ),
+            (
+                ArnError::UnexpectedPart("extra".to_string()),
+                ArnErrorKind::UnexpectedPart,
+            ),
+            (ArnError::InvalidPartFormat, ArnErrorKind::InvalidPartFormat),
+            (
+                ArnError::IdGenerationFailure("clock".to_string()),
+                ArnErrorKind::IdGenerationFailure,
+            ),
+            (
+                ArnError::MissingPart("domain".to_string()),
+                ArnErrorKind::MissingPart,
+            ),
+            (ArnError::InvalidFormat, ArnErrorKind::InvalidFormat),
+            (
+                ArnError::InvalidCharacter {
+                    found: ':',
+                    position: 12,
+                },
+                ArnErrorKind::InvalidCharacter,
+            ),
+            (
+                ArnError::MissingSeparator { position: 3 },
+                ArnErrorKind::MissingSeparator,
+            ),
+            (
+                ArnError::EmptyPart { position: 23 },
+                ArnErrorKind::EmptyPart,
+            ),
+            (
+                ArnError::UnexpectedWhitespace { position: 0 },
+                ArnErrorKind::UnexpectedWhitespace,
+            ),
+            (
+                ArnError::InvalidUtf8 { position: 4 },
+                ArnErrorKind::InvalidUtf8,
+            ),
+            (
+                ArnError::InvalidValue("Domain", "Akton".to_string()),
+                ArnErrorKind::InvalidValue,
+            ),
+            (
+                ArnError::InvalidPart {
+                    index: 1,
+                    value: "a/b".to_string(),
+                },
+                ArnErrorKind::InvalidPart,
+            ),
+            (
+                ArnError::PartIndexOutOfBounds { index: 3, len: 2 },
+                ArnErrorKind::PartIndexOutOfBounds,
+            ),
+            (
+                ArnError::TooLong {
+                    length: 300,
+                    max: 256,
+                },
+                ArnErrorKind::TooLong,
+            ),
+            (
+                ArnError::TooManyParts { count: 3, max: 2 },
+                ArnErrorKind::TooManyParts,
+            ),
+            (ArnError::NonAscii { position: 35 }, ArnErrorKind::NonAscii),
+            (
+                ArnError::MissingPlaceholder("team".to_string()),
+                ArnErrorKind::MissingPlaceholder,
+            ),
+            (ArnError::InfallibleError, ArnErrorKind::InfallibleError),
+        ];
+        for (error, kind) in cases {
+            assert_eq!(error.kind(), kind, "{error}");
+        }
+
+        // Kinds are data-free, so parse failures can be matched without string comparisons.
+        let status = match crate::Arn::try_from("arn:akton:hr").map_err(|e| e.kind()) {
+            Err(ArnErrorKind::MissingSeparator | ArnErrorKind::InvalidFormat) => 400,
+            Err(_) => 422,
+            Ok(_) => 200,
+        };
+        assert_eq!(status, 400);
+    }
+}
@@ -14,38 +14,72 @@
 //! This crate is structured into several modules, each providing distinct functionalities:
 //! - `builder`: Module for building Arns.
 //! - `parser`: Module for parsing Arns.
+//! - `pattern`: Module for matching Arns against wildcard patterns.
 //! - `model`: Contains the models representing different parts of an Arn.
 //! - `traits`: Traits used across the crate for common functionality.
+//! - `arn!`: Macro for building Arns from literal components.
+//!
+//! ## Optional Features
+//! - `std` (default): Enables generating unique root ids. Without it the crate is `no_std` and
+//!   only requires `alloc`; `Root::generate` then returns `ArnError::IdGenerationFailure`.
+//! - `serde`: Implements `Serialize` and `Deserialize` for `Arn`, using its canonical string form.
+//!   Only Arns with the default `/` part separator round-trip.
+//!   The `serde_struct` module serializes the structured form instead, via `#[serde(with = ...)]`.
+//! - `smallvec`: Stores up to four parts inline instead of in a heap-allocated `Vec`.
+//! - `arbitrary`: Implements `arbitrary::Arbitrary` for `Arn<'static>`, generating Arns that
+//!   always display and re-parse, for fuzzing and property tests. Implies `std`.
+//! - `rayon`: Adds `ArnParser::parse_lines_par` for parsing large batches across threads.
+//! - `tracing`: Instruments `ArnParser::parse` with `tracing` spans and debug events.
+//! - `unicode`: Adds opt-in NFC normalization of components, via `ArnParser::nfc`,
+//!   `ArnBuilder::with_nfc`, and `Arn::into_nfc`, so visually identical Arns compare equal.
 //!
 
 #![allow(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
-extern crate core;
+extern crate alloc;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
 mod builder;
 mod errors;
+mod macros;
+mod matcher;
 mod model;
 mod parser;
+mod pattern;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub mod serde_struct;
 mod traits;
+mod tree;
 
 pub mod prelude {
     //! The prelude module for `akton-arn`.
     //!
     //! This module re-exports essential traits and structures for easy use by downstream consumers.
 
-    pub use super::builder::ArnBuilder;
-    pub use super::model::{Account, Arn, Category, Domain, Part, Parts};
-    pub use super::parser::ArnParser;
-    pub use super::traits::ArnComponent;
+    pub use super::builder::{ArnBuilder, QrnBuilder};
+    pub use super::matcher::ArnMatcher;
+    pub use super::model::{Account, Arn, Category, Domain, Part, Parts, Qrn, Scheme};
+    pub use super::parser::{ArnParser, QrnParser};
+    pub use super::pattern::ArnPattern;
+    pub use super::traits::{ArnComponent, SchemeBuilder};
+    pub use super::tree::ArnTree;
 }
 
 // Re-exporting the public API under the root of the crate for direct access
 pub use builder::*;
+pub use errors::{ArnError, ArnErrorKind};
+pub use matcher::*;
 pub use model::*;
 pub use parser::*;
+pub use pattern::*;
 pub use traits::*;
+pub use tree::*;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use std::sync::Once;
     use tracing::Level;
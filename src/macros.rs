@@ -0,0 +1,24 @@
+/// Builds an Arn from literal components, expanding to the equivalent `ArnBuilder` chain.
+///
+/// Takes the domain, category, account, and root, followed by any number of parts, and
+/// evaluates to `Result<Arn, ArnError>`. As with the builder, the root is stored as given.
+///
+/// ```
+/// use akton_arn::arn;
+///
+/// let arn = arn!("akton", "hr", "acct", "root", "teamA", "team1").unwrap();
+/// assert_eq!(arn.domain(), "akton");
+/// assert_eq!(arn.to_string(), "arn:akton:hr:acct:root/teamA/team1");
+/// ```
+#[macro_export]
+macro_rules! arn {
+    ($domain:expr, $category:expr, $account:expr, $root:expr $(, $part:expr)* $(,)?) => {
+        $crate::ArnBuilder::new()
+            .domain($domain)
+            .and_then(|builder| builder.category($category))
+            .and_then(|builder| builder.account($account))
+            .and_then(|builder| builder.root($root))
+            $(.and_then(|builder| builder.part($part)))*
+            .and_then(|builder| builder.build())
+    };
+}
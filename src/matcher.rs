@@ -0,0 +1,171 @@
+use crate::model::Arn;
+use crate::pattern::ArnPattern;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// A set of `ArnPattern`s, indexed for matching an Arn against all of them at once.
+///
+/// Patterns are stored in a prefix trie keyed on their leading literal segments: the scheme,
+/// then each component and part segment up to the first wildcard or placeholder. Matching walks
+/// the trie along the Arn's own segments and only tests the patterns found on that path, so
+/// patterns whose literals differ from the Arn are never looked at.
+#[derive(Debug, Clone, Default)]
+pub struct ArnMatcher {
+    patterns: Vec<ArnPattern>,
+    root: Node,
+}
+
+/// A trie node holding the patterns whose literal prefix ends here.
+#[derive(Debug, Clone, Default)]
+struct Node {
+    patterns: Vec<usize>,
+    children: BTreeMap<String, Node>,
+}
+
+impl ArnMatcher {
+    /// Creates an empty matcher.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a pattern to the set.
+    pub fn add(&mut self, pattern: ArnPattern) {
+        let mut node = &mut self.root;
+        for segment in pattern.literal_prefix() {
+            node = node.children.entry(segment.to_string()).or_default();
+        }
+        node.patterns.push(self.patterns.len());
+        self.patterns.push(pattern);
+    }
+
+    /// Returns the number of patterns in the set.
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Returns true when the set holds no patterns.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Returns true when any pattern in the set matches `arn`.
+    pub fn matches_any(&self, arn: &Arn) -> bool {
+        let mut found = false;
+        self.visit(arn, |_| {
+            found = true;
+            false
+        });
+        found
+    }
+
+    /// Returns every pattern that matches `arn`, in the order they were added.
+    pub fn matching(&self, arn: &Arn) -> Vec<&ArnPattern> {
+        let mut matched = Vec::new();
+        self.visit(arn, |index| {
+            matched.push(index);
+            true
+        });
+        matched.sort_unstable();
+        matched
+            .into_iter()
+            .map(|index| &self.patterns[index])
+            .collect()
+    }
+
+    /// Calls `found` with the index of each matching pattern until it returns false.
+    fn visit(&self, arn: &Arn, mut found: impl FnMut(usize) -> bool) {
+        let components = [arn.domain(), arn.category(), arn.account(), arn.root()];
+        let mut segments = core::iter::once(arn.scheme())
+            .chain(components)
+            .chain(arn.parts().iter().map(|part| part.as_str()));
+        let mut node = Some(&self.root);
+        while let Some(current) = node {
+            for &index in &current.patterns {
+                if self.patterns[index].matches(arn) && !found(index) {
+                    return;
+                }
+            }
+            node = segments
+                .next()
+                .and_then(|segment| current.children.get(segment));
+        }
+    }
+}
+
+impl FromIterator<ArnPattern> for ArnMatcher {
+    fn from_iter<T: IntoIterator<Item = ArnPattern>>(iter: T) -> Self {
+        let mut matcher = ArnMatcher::new();
+        matcher.extend(iter);
+        matcher
+    }
+}
+
+impl Extend<ArnPattern> for ArnMatcher {
+    fn extend<T: IntoIterator<Item = ArnPattern>>(&mut self, iter: T) {
+        for pattern in iter {
+            self.add(pattern);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArnParser;
+    use alloc::format;
+
+    fn patterns() -> Vec<ArnPattern> {
+        let mut patterns = Vec::new();
+        for domain in ["akton", "quasar", "*"] {
+            for account in ["acct1", "acct2", "acct*", "{account}"] {
+                for team in 0..20 {
+                    for path in ["", "/*", "/**", "/member", "/*/member"] {
+                        let source = format!("arn:{domain}:hr:{account}:root/team{team}{path}");
+                        patterns.push(ArnPattern::parse(&source).unwrap());
+                    }
+                }
+            }
+        }
+        patterns.push(ArnPattern::parse("arn:*:*:*:*/**").unwrap());
+        patterns.push(ArnPattern::parse("qrn:akton:hr:acct1:root/**").unwrap());
+        patterns
+    }
+
+    #[test]
+    fn test_matcher_agrees_with_linear_scan() {
+        let patterns = patterns();
+        let matcher: ArnMatcher = patterns.iter().cloned().collect();
+        assert_eq!(matcher.len(), patterns.len());
+
+        for domain in ["akton", "quasar", "other"] {
+            for account in ["acct1", "acct3", "other"] {
+                for path in [
+                    "root",
+                    "root/team3",
+                    "root/team3/member",
+                    "root/team19/x/member",
+                    "root/team7/widget",
+                    "root/nobody",
+                ] {
+                    let arn = ArnParser::new(format!("arn:{domain}:hr:{account}:{path}"))
+                        .parse()
+                        .unwrap();
+                    let expected: Vec<&ArnPattern> =
+                        patterns.iter().filter(|p| p.matches(&arn)).collect();
+                    assert_eq!(matcher.matching(&arn), expected, "{arn}");
+                    assert_eq!(matcher.matches_any(&arn), !expected.is_empty(), "{arn}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_matcher() {
+        let matcher = ArnMatcher::new();
+        let arn = ArnParser::new("arn:akton:hr:acct:root").parse().unwrap();
+        assert!(matcher.is_empty());
+        assert!(!matcher.matches_any(&arn));
+        assert!(matcher.matching(&arn).is_empty());
+    }
+}
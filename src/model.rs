@@ -1,15 +1,30 @@
 mod account;
 mod arn;
+mod aws;
 mod category;
+mod compact;
+mod diff;
 mod domain;
+#[cfg(feature = "unicode")]
+mod nfc;
 mod part;
 mod parts;
 mod root;
+mod scheme;
+mod uri;
 
 pub use account::Account;
-pub use arn::Arn;
-pub use category::Category;
+pub use arn::{Arn, Qrn};
+pub use category::{Category, KnownCategory};
+pub use compact::CompactArn;
+pub use diff::{ArnDiff, ComponentChange, PartChange};
 pub use domain::Domain;
+#[cfg(feature = "unicode")]
+pub(crate) use nfc::nfc;
 pub use part::Part;
 pub use parts::Parts;
+pub(crate) use parts::{check_part_separator, DEFAULT_PART_SEPARATOR};
 pub use root::Root;
+pub(crate) use root::{is_root_char, validate_root};
+pub use scheme::Scheme;
+pub(crate) use scheme::{DEFAULT_SCHEME, QRN_SCHEME};
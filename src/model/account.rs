@@ -1,17 +1,32 @@
-use derive_more::{AsRef, From, Into};
-use std::borrow::Cow;
-use std::fmt;
+use crate::errors::ArnError;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::fmt;
+use derive_more::{AsRef, Deref, Into};
 /// Represents an account identifier in the Arn system.
-
-#[derive(AsRef, From, Into, Eq, Debug, PartialEq, Clone, Hash)]
+///
+/// Accounts cannot be empty or contain the `:` and `/` separators. An account derefs to `str`,
+/// exposing the identifier to string methods and `&str` parameters.
+#[derive(AsRef, Deref, Into, Eq, Debug, PartialEq, Clone, Hash, PartialOrd, Ord)]
+#[deref(forward)]
 pub struct Account<'a>(pub(crate) Cow<'a, str>);
 
 impl<'a> Account<'a> {
     pub fn as_str(&self) -> &str {
         &self.0
     }
-    pub fn new(value: impl Into<Cow<'a, str>>) -> Self {
-        Account(value.into())
+    pub fn new(value: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
+        let value = value.into();
+        if value.is_empty() {
+            return Err(ArnError::ParseFailure(
+                "Account",
+                "cannot be empty".to_string(),
+            ));
+        }
+        if value.contains(':') || value.contains('/') {
+            return Err(ArnError::InvalidValue("Account", value.into_owned()));
+        }
+        Ok(Account(value))
     }
     pub fn into_owned(self) -> Account<'static> {
         Account(Cow::Owned(self.0.into_owned()))
@@ -30,11 +45,11 @@ impl<'a> fmt::Display for Account<'a> {
     }
 }
 
-impl<'a> std::str::FromStr for Account<'a> {
-    type Err = std::convert::Infallible;
+impl<'a> core::str::FromStr for Account<'a> {
+    type Err = ArnError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Account(Cow::Owned(s.to_owned())))
+        Account::new(s.to_string())
     }
 }
 impl<'a> From<Account<'a>> for String {
@@ -45,11 +60,24 @@ impl<'a> From<Account<'a>> for String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_account_derefs_to_str() -> anyhow::Result<()> {
+        fn takes_str(value: &str) -> usize {
+            value.len()
+        }
+        let account = Account::new("company123")?;
+        assert_eq!(takes_str(&account), 10);
+        assert!(account.contains("pany"));
+        Ok(())
+    }
 
     #[test]
-    fn test_account_creation() {
-        let account = Account::new("test123");
+    fn test_account_creation() -> anyhow::Result<()> {
+        let account = Account::new("test123")?;
         assert_eq!(account.as_str(), "test123");
+        Ok(())
     }
 
     #[test]
@@ -59,9 +87,10 @@ mod tests {
     }
 
     #[test]
-    fn test_account_display() {
-        let account = Account::new("example456");
+    fn test_account_display() -> anyhow::Result<()> {
+        let account = Account::new("example456")?;
         assert_eq!(format!("{}", account), "example456");
+        Ok(())
     }
 
     #[test]
@@ -71,18 +100,36 @@ mod tests {
     }
 
     #[test]
-    fn test_account_equality() {
-        let account1 = Account::new("test123");
-        let account2 = Account::new("test123");
-        let account3 = Account::new("other456");
+    fn test_account_equality() -> anyhow::Result<()> {
+        let account1 = Account::new("test123")?;
+        let account2 = Account::new("test123")?;
+        let account3 = Account::new("other456")?;
         assert_eq!(account1, account2);
         assert_ne!(account1, account3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_account_rejects_invalid_values() {
+        assert!(matches!(
+            Account::new(""),
+            Err(ArnError::ParseFailure("Account", _))
+        ));
+        assert_eq!(
+            Account::new("acct:123"),
+            Err(ArnError::InvalidValue("Account", "acct:123".to_string()))
+        );
+        assert_eq!(
+            Account::new("acct/123"),
+            Err(ArnError::InvalidValue("Account", "acct/123".to_string()))
+        );
     }
 
     #[test]
-    fn test_account_into_string() {
-        let account = Account::new("test123");
+    fn test_account_into_string() -> anyhow::Result<()> {
+        let account = Account::new("test123")?;
         let string: String = account.into();
         assert_eq!(string, "test123");
+        Ok(())
     }
 }
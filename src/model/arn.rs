@@ -1,36 +1,270 @@
 use crate::errors::ArnError;
-use crate::{Account, ArnComponent, Category, Domain, Part, Parts, Root};
-use std::borrow::Cow;
-use std::fmt;
-use std::fmt::{Display, Formatter};
+use crate::model::{validate_root, DEFAULT_PART_SEPARATOR};
+use crate::{
+    Account, ArnBuilder, ArnDiff, ArnParser, ArnPattern, Category, Domain, DynScheme, Part, Parts,
+    Root, Scheme, SchemeKind, SchemeMarker,
+};
+use alloc::borrow::Cow;
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
 
 /// Represents an Akton Resource Name (Arn), which uniquely identifies resources within the Akton framework.
-#[derive(Debug, PartialEq, Clone, Eq)]
-pub struct Arn<'a> {
-    pub domain: Domain<'a>,
-    pub category: Category<'a>,
-    pub account: Account<'a>,
-    pub root: Root<'a>,
-    pub parts: Parts<'a>,
+///
+/// Equality and hashing use the text of each component, so borrowed and owned Arns with the
+/// same contents are equal and hash identically regardless of how they were constructed. The
+/// part separator is compared too, since Arns with different separators render differently.
+/// Because the canonical string is not stored, `Arn` cannot implement `Borrow<str>`; store
+/// `CompactArn` values instead to look a set up by string. The input kept by
+/// `ArnParser::keep_original` is ignored by all of these comparisons.
+///
+/// Arns are ordered component by component: domain, category, account, root, and then each
+/// part in turn, with the scheme breaking any remaining ties. Parts compare lexicographically,
+/// and an Arn sorts before its descendants, so siblings in the hierarchy cluster together.
+///
+/// The components are private so that every Arn holds values that passed validation. Read them
+/// through accessors such as `domain()` and `parts()`, and derive modified Arns through
+/// validating methods such as `replace_account` or `append_part`:
+///
+/// ```compile_fail
+/// let mut arn = akton_arn::Arn::try_from("arn:akton:hr:acct:root").unwrap();
+/// arn.account = akton_arn::Account::new("other").unwrap();
+/// ```
+///
+/// Nor can components be made from unchecked strings, or assembled without validation:
+///
+/// ```compile_fail
+/// let domain = akton_arn::Domain::from(std::borrow::Cow::Borrowed("Bad Domain"));
+/// ```
+///
+/// ```compile_fail
+/// let category = akton_arn::Category::from(std::borrow::Cow::Borrowed("hr:payroll"));
+/// ```
+///
+/// ```compile_fail
+/// let account = akton_arn::Account::from(std::borrow::Cow::Borrowed("acct:other"));
+/// ```
+///
+/// ```compile_fail
+/// let root = akton_arn::Root::from(std::borrow::Cow::Borrowed("root/sub"));
+/// ```
+///
+/// ```compile_fail
+/// let part = akton_arn::Part::from(std::borrow::Cow::Borrowed("team/a"));
+/// ```
+///
+/// ```compile_fail
+/// use akton_arn::*;
+/// let arn = Arn::new(
+///     Domain::default(),
+///     Category::default(),
+///     Account::default(),
+///     Root::default(),
+///     Parts::default(),
+/// );
+/// ```
+///
+/// The `S` parameter is the `SchemeKind`. The default, `DynScheme`, stores the scheme, which the
+/// parser and `replace_scheme` set at runtime. An Arn built by a builder with a `SchemeMarker`
+/// such as `QrnScheme` stores no scheme and writes the marker's; convert it with `into_dyn` to
+/// use the methods of the default kind.
+#[derive(Debug, Clone)]
+pub struct Arn<'a, S: SchemeKind = DynScheme> {
+    pub(crate) domain: Domain<'a>,
+    pub(crate) category: Category<'a>,
+    pub(crate) account: Account<'a>,
+    pub(crate) root: Root<'a>,
+    pub(crate) parts: Parts<'a>,
+    pub(crate) scheme: S::Value,
+    pub(crate) part_separator: char,
+    /// The exact input this Arn was parsed from, kept by `ArnParser::keep_original` until the
+    /// Arn is modified.
+    pub(crate) original: Option<Cow<'a, str>>,
 }
 
-impl Display for Arn<'_> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let mut display = format!(
-            "{}{}:{}:{}:{}",
-            Domain::prefix(),
+impl<'a, S: SchemeKind> Arn<'a, S> {
+    /// The fields that equality, ordering, and hashing use, in order.
+    #[allow(clippy::type_complexity)]
+    fn key(
+        &self,
+    ) -> (
+        &Domain<'a>,
+        &Category<'a>,
+        &Account<'a>,
+        &Root<'a>,
+        &Parts<'a>,
+        &str,
+        char,
+    ) {
+        (
+            &self.domain,
+            &self.category,
+            &self.account,
+            &self.root,
+            &self.parts,
+            self.scheme(),
+            self.part_separator,
+        )
+    }
+
+    /// Writes the canonical form, ignoring any kept original input.
+    pub(crate) fn write_canonical(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}:{}:{}",
+            self.scheme(),
             self.domain,
             self.category,
             self.account,
             self.root
-        );
-        if !self.parts.0.is_empty() {
-            display = format!("{}/{}", display, self.parts);
+        )?;
+        for part in self.parts.0.iter() {
+            write!(f, "{}{}", self.part_separator, part)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the exact input this Arn was parsed from, if the parser was configured with
+    /// `ArnParser::keep_original`.
+    ///
+    /// Every method that derives a modified Arn, such as `append_part` or `replace_account`,
+    /// drops the original so that `Display` falls back to the canonical form. Clones and
+    /// `into_owned` keep it.
+    pub fn original(&self) -> Option<&str> {
+        self.original.as_deref()
+    }
+
+    /// Returns the scheme, e.g. `arn`, without allocating.
+    pub fn scheme(&self) -> &str {
+        S::as_str(&self.scheme)
+    }
+
+    /// Returns the domain component without allocating.
+    pub fn domain(&self) -> &str {
+        self.domain.as_str()
+    }
+
+    /// Returns the category component without allocating.
+    pub fn category(&self) -> &str {
+        self.category.as_str()
+    }
+
+    /// Returns the account component without allocating.
+    pub fn account(&self) -> &str {
+        self.account.as_str()
+    }
+
+    /// Returns the root component without allocating.
+    pub fn root(&self) -> &str {
+        self.root.as_str()
+    }
+
+    /// Returns the parts that follow the root.
+    pub fn parts(&self) -> &Parts<'a> {
+        &self.parts
+    }
+
+    /// Returns the separator placed between the root and each part, `/` unless configured.
+    pub fn part_separator(&self) -> char {
+        self.part_separator
+    }
+}
+
+impl<S: SchemeKind> PartialEq for Arn<'_, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<S: SchemeKind> Eq for Arn<'_, S> {}
+
+impl<S: SchemeKind> core::hash::Hash for Arn<'_, S> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl<S: SchemeKind> PartialOrd for Arn<'_, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: SchemeKind> Ord for Arn<'_, S> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<'a, S: SchemeMarker> Arn<'a, S> {
+    /// Converts into an Arn of the default `DynScheme` kind, storing the marker's scheme, to
+    /// use the methods that kind offers.
+    pub fn into_dyn(self) -> Arn<'a> {
+        Arn {
+            domain: self.domain,
+            category: self.category,
+            account: self.account,
+            root: self.root,
+            parts: self.parts,
+            scheme: Scheme(Cow::Borrowed(S::SCHEME)),
+            part_separator: self.part_separator,
+            original: self.original,
+        }
+    }
+}
+
+impl<'a, S: SchemeMarker> From<Arn<'a, S>> for Arn<'a> {
+    fn from(arn: Arn<'a, S>) -> Self {
+        arn.into_dyn()
+    }
+}
+
+/// A Quasar Resource Name. `Qrn` and `Arn` are the same type and can be used interchangeably;
+/// a Qrn is simply an Arn whose scheme is `qrn`, as produced by `QrnParser` or by `into_dyn` on
+/// what `QrnBuilder` builds.
+pub type Qrn<'a> = Arn<'a>;
+
+/// Writes the canonical form, e.g. `arn:akton:hr:acct:root/teamA/team1`.
+///
+/// An Arn parsed with `ArnParser::keep_original` and not modified since writes its original
+/// input byte for byte instead.
+///
+/// A width, e.g. `{:<60}`, pads the whole Arn with the requested fill and alignment. Use
+/// `to_columns` to lay the components out in a table instead.
+///
+/// The alternate flag (`{:#}`) writes a labeled rendering for logs and debugging instead,
+/// e.g. `domain=akton category=hr account=acct root=root parts=[teamA, team1]`.
+impl<S: SchemeKind> Display for Arn<'_, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                "domain={} category={} account={} root={} parts=[",
+                self.domain, self.category, self.account, self.root
+            )?;
+            for (i, part) in self.parts.0.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{}", part)?;
+            }
+            return f.write_str("]");
+        }
+        match &self.original {
+            Some(original) => f.pad(original),
+            None if f.width().is_some() => {
+                let mut text = String::new();
+                self.write_canonical(&mut text)?;
+                f.pad(&text)
+            }
+            None => self.write_canonical(f),
         }
-        write!(f, "{}", display)
     }
 }
-use std::ops::Add;
+use core::ops::Add;
 
 impl<'a> Add for Arn<'a> {
     type Output = Arn<'a>;
@@ -44,12 +278,18 @@ impl<'a> Add for Arn<'a> {
             account: self.account,
             root: self.root,
             parts: Parts(new_parts),
+            scheme: self.scheme,
+            part_separator: self.part_separator,
+            original: None,
         }
     }
 }
 impl<'a> Arn<'a> {
-    /// Creates a new Arn with the given components.
-    pub fn new(
+    /// Creates a new Arn with the given components and the default `arn` scheme.
+    ///
+    /// Crate-internal: outside code builds Arns through `ArnBuilder`, `ArnParser`, or
+    /// `Arn::try_new`, which validate every component.
+    pub(crate) fn new(
         domain: Domain<'a>,
         category: Category<'a>,
         account: Account<'a>,
@@ -62,10 +302,29 @@ impl<'a> Arn<'a> {
             account,
             root,
             parts,
+            scheme: Scheme::default(),
+            part_separator: DEFAULT_PART_SEPARATOR,
+            original: None,
+        }
+    }
+
+    /// Moves the components into an Arn of kind `S`, which drops the stored scheme when `S` is
+    /// a `SchemeMarker`.
+    pub(crate) fn into_kind<S: SchemeKind>(self) -> Arn<'a, S> {
+        Arn {
+            domain: self.domain,
+            category: self.category,
+            account: self.account,
+            root: self.root,
+            parts: self.parts,
+            scheme: S::from_scheme(self.scheme),
+            part_separator: self.part_separator,
+            original: self.original,
         }
     }
 
     /// Creates a new Arn with the given root and default values for other fields
+    #[cfg(feature = "std")]
     pub fn with_root(root: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
         let root = Root::new(root)?;
         Ok(Arn {
@@ -74,18 +333,23 @@ impl<'a> Arn<'a> {
         })
     }
 
-    /// Creates a new Arn based on an existing Arn but with a new root
+    /// Creates a new Arn based on an existing Arn but with a root minted by `Root::generate`
+    /// from the given prefix
     pub fn with_new_root(&self, new_root: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
-        let new_root = Root::new(new_root)?;
+        let new_root = Root::generate(&new_root.into())?;
         Ok(Arn {
             domain: self.domain.clone(),
             category: self.category.clone(),
             account: self.account.clone(),
             root: new_root,
             parts: self.parts.clone(),
+            scheme: self.scheme.clone(),
+            part_separator: self.part_separator,
+            original: None,
         })
     }
 
+    #[cfg(feature = "std")]
     pub fn with_domain(domain: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
         let domain = Domain::new(domain)?;
         Ok(Arn {
@@ -94,28 +358,98 @@ impl<'a> Arn<'a> {
             account: Account::default(),
             root: Root::default(),
             parts: Parts::default(),
+            scheme: Scheme::default(),
+            part_separator: DEFAULT_PART_SEPARATOR,
+            original: None,
         })
     }
 
+    #[cfg(feature = "std")]
     pub fn with_category(category: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
-        let category = Category::new(category);
+        let category = Category::new(category)?;
         Ok(Arn {
             domain: Domain::default(),
             category,
             account: Account::default(),
             root: Root::default(),
             parts: Parts::default(),
+            scheme: Scheme::default(),
+            part_separator: DEFAULT_PART_SEPARATOR,
+            original: None,
         })
     }
 
+    #[cfg(feature = "std")]
     pub fn with_account(account: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
-        let account = Account::new(account);
+        let account = Account::new(account)?;
         Ok(Arn {
             domain: Domain::default(),
             category: Category::default(),
             account,
             root: Root::default(),
             parts: Parts::default(),
+            scheme: Scheme::default(),
+            part_separator: DEFAULT_PART_SEPARATOR,
+            original: None,
+        })
+    }
+
+    /// Returns this Arn with its account replaced, e.g. when cloning a resource across accounts.
+    ///
+    /// The replacement is validated like `Account::new`; every other component is kept as is.
+    /// Named `replace_account` because `with_account` already builds an Arn from defaults.
+    pub fn replace_account(self, account: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
+        Ok(Arn {
+            account: Account::new(account)?,
+            original: None,
+            ..self
+        })
+    }
+
+    /// Returns this Arn with its category replaced, rejecting a category containing `:`.
+    pub fn replace_category(self, category: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
+        Ok(Arn {
+            category: Category::new(category)?,
+            original: None,
+            ..self
+        })
+    }
+
+    /// Returns this Arn with its scheme replaced, validated like `Scheme::new`.
+    pub fn replace_scheme(self, scheme: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
+        Ok(Arn {
+            scheme: Scheme::new(scheme)?.into_static(),
+            original: None,
+            ..self
+        })
+    }
+
+    /// Returns this Arn with its domain replaced, e.g. when moving between internal and
+    /// external domains.
+    ///
+    /// The replacement is validated like `Domain::new`; every other component is kept as is.
+    pub fn replace_domain(self, domain: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
+        Ok(Arn {
+            domain: Domain::new(domain)?,
+            original: None,
+            ..self
+        })
+    }
+
+    /// Returns this Arn with its root replaced, e.g. when promoting a resource under a new root
+    /// namespace.
+    ///
+    /// The replacement is an existing root, such as `root_01h455vb4pex5vsknk084sn02q`, stored
+    /// verbatim after checking it against the root charset of `Root`; every other component is
+    /// kept as is. Named `replace_root` because `with_root` already builds an Arn from defaults.
+    /// Use `with_new_root` to mint a fresh root id from a prefix instead.
+    pub fn replace_root(self, root: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
+        let root = root.into();
+        validate_root(&root)?;
+        Ok(Arn {
+            root: Root(root),
+            original: None,
+            ..self
         })
     }
 
@@ -128,9 +462,88 @@ impl<'a> Arn<'a> {
             account: self.account.clone(),
             root: self.root.clone(),
             parts: new_parts,
+            scheme: self.scheme.clone(),
+            part_separator: self.part_separator,
+            original: None,
         })
     }
 
+    /// Consumes the Arn and returns a child Arn with `part` appended to its parts.
+    ///
+    /// The segment is validated by `Part::new`, so values containing `/` or `:` are rejected.
+    pub fn append_part(mut self, part: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
+        self.parts.0.push(Part::new(part)?);
+        self.original = None;
+        Ok(self)
+    }
+
+    /// Consumes the Arn and removes its last part, returning the shortened Arn and the removed part.
+    ///
+    /// When the Arn has no parts it is returned unchanged alongside `None`.
+    pub fn pop_part(mut self) -> (Self, Option<Part<'a>>) {
+        let part = self.parts.0.pop();
+        self.original = None;
+        (self, part)
+    }
+
+    /// Consumes the Arn and replaces the part at `index`, keeping its position, e.g. to rename
+    /// `team1` to `team2`.
+    ///
+    /// The new segment is validated like `Part::new` and must not contain the part separator.
+    /// Fails with `ArnError::PartIndexOutOfBounds` when `index` is not below the number of parts.
+    pub fn replace_part(
+        mut self,
+        index: usize,
+        value: impl Into<Cow<'a, str>>,
+    ) -> Result<Arn<'a>, ArnError> {
+        let len = self.parts.0.len();
+        if index >= len {
+            return Err(ArnError::PartIndexOutOfBounds { index, len });
+        }
+        let value = value.into();
+        if value.contains(self.part_separator) {
+            return Err(ArnError::InvalidPartFormat);
+        }
+        self.parts.0[index] = Part::new(value)?;
+        self.original = None;
+        Ok(self)
+    }
+
+    /// Consumes the Arn and rewrites every part with `f`, e.g. to lowercase the resource path.
+    ///
+    /// Each produced segment is validated like `Part::new` and must not contain the part
+    /// separator; the first one that fails is reported as `ArnError::InvalidPart` with its index.
+    pub fn map_parts<F>(mut self, mut f: F) -> Result<Arn<'a>, ArnError>
+    where
+        F: FnMut(&Part) -> String,
+    {
+        let separator = self.part_separator;
+        self.parts = self
+            .parts
+            .iter()
+            .enumerate()
+            .map(|(index, part)| {
+                let value = f(part);
+                match Part::new(value.clone()) {
+                    Ok(part) if !value.contains(separator) => Ok(part),
+                    _ => Err(ArnError::InvalidPart { index, value }),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        self.original = None;
+        Ok(self)
+    }
+
+    /// Consumes the Arn and keeps only the parts for which `f` returns true, in order.
+    pub fn filter_parts<F>(mut self, mut f: F) -> Arn<'a>
+    where
+        F: FnMut(&Part) -> bool,
+    {
+        self.parts.0.retain(|part| f(part));
+        self.original = None;
+        self
+    }
+
     pub fn with_parts(
         &self,
         parts: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
@@ -141,19 +554,444 @@ impl<'a> Arn<'a> {
             category: self.category.clone(),
             account: self.account.clone(),
             root: self.root.clone(),
-            parts: Parts(new_parts?),
+            parts: Parts::new(new_parts?),
+            scheme: self.scheme.clone(),
+            part_separator: self.part_separator,
+            original: None,
         })
     }
 
-    pub fn is_child_of(&self, other: &Arn) -> bool {
-        self.domain == other.domain
+    /// Converts the Arn into an owned version with 'static lifetime.
+    ///
+    /// Every component is copied into a `Cow::Owned`, so the result no longer borrows from the
+    /// string it was parsed from and can be stored in long-lived structures.
+    pub fn into_owned(self) -> Arn<'static> {
+        Arn {
+            domain: self.domain.into_owned(),
+            category: self.category.into_owned(),
+            account: self.account.into_owned(),
+            root: self.root.into_owned(),
+            parts: self.parts.into_owned(),
+            scheme: self.scheme.into_owned(),
+            part_separator: self.part_separator,
+            original: self
+                .original
+                .map(|original| Cow::Owned(original.into_owned())),
+        }
+    }
+
+    /// Creates an Arn from the five raw components, validating each, for callers that already
+    /// have them and do not need the builder.
+    ///
+    /// The domain, category, and account are checked as `ArnBuilder::with` checks them. The root
+    /// is stored verbatim, as the parser stores it, and must be non-empty and within the root
+    /// charset of `Root`. An invalid part fails with `ArnError::InvalidPart` carrying its index.
+    /// The scheme is `arn`.
+    pub fn try_new<P>(
+        domain: impl Into<Cow<'a, str>>,
+        category: impl Into<Cow<'a, str>>,
+        account: impl Into<Cow<'a, str>>,
+        root: impl Into<Cow<'a, str>>,
+        parts: impl IntoIterator<Item = P>,
+    ) -> Result<Arn<'a>, ArnError>
+    where
+        P: Into<Cow<'a, str>>,
+    {
+        let domain = Domain::new(domain)?;
+        let category = Category::new(category)?;
+        let account = Account::new(account)?;
+        let root = root.into();
+        validate_root(&root)?;
+        let parts = parts
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let value = value.into();
+                Part::new(value.clone()).map_err(|_| ArnError::InvalidPart {
+                    index,
+                    value: value.into_owned(),
+                })
+            })
+            .collect::<Result<Parts, _>>()?;
+        Ok(Arn::new(domain, category, account, Root(root), parts))
+    }
+
+    /// Validates owned component values like `try_new` and assembles them into an Arn, for
+    /// decoders of forms other than the Arn string. A missing scheme means `arn`.
+    pub(crate) fn from_owned_components(
+        scheme: Option<String>,
+        domain: String,
+        category: String,
+        account: String,
+        root: String,
+        parts: Vec<String>,
+    ) -> Result<Arn<'static>, ArnError> {
+        let mut arn = Arn::try_new(domain, category, account, root, parts)?;
+        if let Some(scheme) = scheme {
+            arn.scheme = Scheme::new(scheme)?;
+        }
+        Ok(arn)
+    }
+
+    /// Rewrites the Arn into its canonical form, so that semantically equal Arns also compare
+    /// and display equal.
+    ///
+    /// The canonical form is the one `Display` writes from the components: the lowercase scheme,
+    /// then each component and part joined by their separators. Every textual variation of the
+    /// same Arn is resolved while it is parsed, as parts can never be empty:
+    ///
+    /// * `ArnParser::lenient` trims surrounding whitespace and drops trailing and doubled part
+    ///   separators, which the strict parser rejects;
+    /// * the scheme is matched case-insensitively and stored lowercase.
+    ///
+    /// All that is left for `normalize` is to drop an original input kept with
+    /// `ArnParser::keep_original`, so the Arn displays in its canonical form. The components,
+    /// including any percent-escapes in the parts, are kept exactly as they are. Normalizing is
+    /// idempotent.
+    pub fn normalize(mut self) -> Arn<'a> {
+        self.original = None;
+        self
+    }
+
+    /// Maps the root and parts onto a relative filesystem path, e.g. `root/teamA/team1`, joined
+    /// with the platform separator.
+    ///
+    /// Parts are never empty, so every part becomes exactly one path component, and an Arn
+    /// without parts maps to just its root. Parts such as `..` are passed through unchanged, so
+    /// sanitize untrusted Arns before touching the filesystem.
+    #[cfg(feature = "std")]
+    pub fn to_path(&self) -> std::path::PathBuf {
+        let mut path = std::path::PathBuf::from(self.root.as_str());
+        path.extend(self.parts.iter().map(Part::as_str));
+        path
+    }
+
+    /// Returns the five logical columns, domain, category, account, root, and parts, for a table
+    /// formatter, e.g. `["akton", "hr", "acct", "root", "teamA/team1"]`.
+    ///
+    /// The parts column joins the parts with the part separator and is empty without parts. The
+    /// scheme is not included.
+    pub fn to_columns(&self) -> [String; 5] {
+        let mut parts = String::new();
+        for (index, part) in self.parts.iter().enumerate() {
+            if index > 0 {
+                parts.push(self.part_separator);
+            }
+            parts.push_str(part.as_str());
+        }
+        [
+            self.domain().to_string(),
+            self.category().to_string(),
+            self.account().to_string(),
+            self.root().to_string(),
+            parts,
+        ]
+    }
+
+    /// Returns the parts joined with `/`, e.g. `teamA/team1`, on every platform.
+    ///
+    /// Unlike `to_path`, the root is not included. An Arn without parts yields an empty string.
+    pub fn parts_path(&self) -> String {
+        self.parts.to_string()
+    }
+
+    /// Returns the resource portion after the account, the root joined with each part by `/`,
+    /// e.g. `root/teamA/team1`. An Arn without parts yields just its root.
+    pub fn root_path(&self) -> String {
+        let mut path = self.root.as_str().to_string();
+        for part in self.parts.iter() {
+            path.push('/');
+            path.push_str(part.as_str());
+        }
+        path
+    }
+
+    /// Returns true if any part's decoded value equals `value`. See `Parts::contains`.
+    pub fn contains_part(&self, value: &str) -> bool {
+        self.parts.contains(value)
+    }
+
+    /// Returns an iterator over the domain, category, account, root, and then each part, in the
+    /// order they are serialized. The scheme is not included.
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        [
+            self.domain.as_str(),
+            self.category.as_str(),
+            self.account.as_str(),
+            self.root.as_str(),
+        ]
+        .into_iter()
+        .chain(self.parts.iter().map(Part::as_str))
+    }
+
+    /// Compares this Arn with `other` component by component, reporting what changed.
+    ///
+    /// See `ArnDiff` for how parts are compared and how the report is displayed.
+    pub fn diff<'s>(&'s self, other: &'s Arn) -> ArnDiff<'s> {
+        ArnDiff::new(self, other)
+    }
+
+    /// Returns how many part segments deep the Arn is; an Arn without parts has depth 0.
+    pub fn depth(&self) -> usize {
+        self.parts.len()
+    }
+
+    /// Keeps at most the first `n` parts, dropping the deeper ones.
+    ///
+    /// Trimming to a depth at or beyond the current depth returns the Arn unchanged, and
+    /// trimming to 0 yields the part-less root Arn.
+    pub fn trim_to_depth(mut self, n: usize) -> Arn<'a> {
+        self.parts.0.truncate(n);
+        self.original = None;
+        self
+    }
+
+    /// Returns an iterator over the Arn and each of its ancestors, ending with the part-less root Arn.
+    ///
+    /// An Arn without parts yields exactly one item: itself.
+    pub fn ancestors(&self) -> impl Iterator<Item = Arn<'a>> {
+        core::iter::successors(Some(self.clone()), Arn::parent)
+    }
+
+    /// Returns the parts of the Arn and of each of its ancestors, as progressively shorter
+    /// slices of its own parts, ending with the empty slice of the root Arn.
+    ///
+    /// This walks the same chain as `ancestors` without building a new `Arn` at each step. The
+    /// other components are shared by every ancestor and can be read from `self`.
+    pub fn ancestor_parts(&self) -> impl Iterator<Item = &[Part<'a>]> {
+        let parts: &[Part<'a>] = &self.parts.0;
+        (0..=parts.len()).rev().map(move |len| &parts[..len])
+    }
+
+    /// Calls `f` with the parts of the Arn and of each ancestor in turn, as `ancestor_parts`
+    /// yields them, without allocating.
+    pub fn for_each_ancestor<'s, F>(&'s self, f: F)
+    where
+        F: FnMut(&'s [Part<'a>]),
+    {
+        self.ancestor_parts().for_each(f);
+    }
+
+    /// Checks whether the Arn matches a wildcard pattern such as `arn:akton:*:acct:root/*`.
+    ///
+    /// A `*` matches any run of characters within a single component or part segment; it never
+    /// crosses a `:` or `/` boundary. A part segment of `**` matches zero or more whole part
+    /// segments. The scheme must match literally. Returns an error if the pattern is malformed.
+    ///
+    /// Use `ArnPattern` directly to avoid re-parsing a pattern matched against many Arns.
+    pub fn matches(&self, pattern: &str) -> Result<bool, ArnError> {
+        Ok(ArnPattern::parse(pattern)?.matches(self))
+    }
+
+    /// Checks whether `s` is exactly the Arn's canonical form, treating `*` as an ordinary
+    /// character rather than a wildcard.
+    ///
+    /// Spelled out to contrast with `matches` at call sites. Unlike `arn == s`, an original input
+    /// kept with `ArnParser::keep_original` is ignored, so the Arn always compares by its
+    /// canonical form.
+    pub fn equals_str(&self, s: &str) -> bool {
+        self.canonical_eq(s)
+    }
+
+    /// Compares `other` against the canonical form as it is written, without allocating it.
+    fn canonical_eq(&self, other: &str) -> bool {
+        /// Consumes the expected text as the Arn is written, failing on the first mismatch.
+        struct Expect<'s>(&'s str);
+
+        impl fmt::Write for Expect<'_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 = self.0.strip_prefix(s).ok_or(fmt::Error)?;
+                Ok(())
+            }
+        }
+
+        let mut expect = Expect(other);
+        self.write_canonical(&mut expect).is_ok() && expect.0.is_empty()
+    }
+
+    /// Renders the canonical Arn with the account replaced by `****`, for logging Arns without
+    /// leaking account identifiers.
+    pub fn redact_account(&self) -> String {
+        Arn {
+            account: Account(Cow::Borrowed("****")),
+            original: None,
+            ..self.clone()
+        }
+        .to_string()
+    }
+
+    /// Fills the `{name}` placeholders in `template` from `vars` and parses the result, e.g.
+    /// `Arn::format("arn:akton:hr:{account}:root/{team}", &vars)`.
+    ///
+    /// Values are substituted verbatim, so a value containing `/` adds parts, and the filled
+    /// string is then validated like any other. A placeholder missing from `vars` fails with
+    /// `ArnError::MissingPlaceholder` naming it, and a `{` without a closing `}` with
+    /// `ArnError::InvalidCharacter` at its position.
+    #[cfg(feature = "std")]
+    pub fn format(
+        template: &str,
+        vars: &std::collections::HashMap<&str, &str>,
+    ) -> Result<Arn<'static>, ArnError> {
+        let mut filled = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(open) = rest.find('{') {
+            filled.push_str(&rest[..open]);
+            let close = rest[open..].find('}').ok_or(ArnError::InvalidCharacter {
+                found: '{',
+                position: template.len() - rest.len() + open,
+            })?;
+            let name = &rest[open + 1..open + close];
+            let value = vars
+                .get(name)
+                .ok_or_else(|| ArnError::MissingPlaceholder(name.to_string()))?;
+            filled.push_str(value);
+            rest = &rest[open + close + 1..];
+        }
+        filled.push_str(rest);
+        ArnParser::new(filled).parse()
+    }
+
+    /// Returns whether `input` is a valid Arn, without building it; see `ArnParser::validate`.
+    pub fn is_valid(input: &str) -> bool {
+        ArnParser::new(input).validate().is_ok()
+    }
+
+    /// Returns a new builder starting at the `Domain` state; shorthand for `ArnBuilder::new()`.
+    pub fn builder() -> ArnBuilder<'a, Domain<'a>> {
+        ArnBuilder::new()
+    }
+
+    /// Returns a builder in the `Parts` state seeded with this Arn, for adding parts and rebuilding.
+    pub fn to_builder(&self) -> ArnBuilder<'a, Parts<'a>> {
+        ArnBuilder::from_arn(self.clone())
+    }
+
+    /// Compares two Arns with ASCII case folding applied to every component.
+    ///
+    /// The scheme, domain, category, account, root, and each part segment are all folded, so
+    /// `arn:akton:HR:Acct:root/TeamA` equals `arn:akton:hr:acct:root/teama`. Parts must still
+    /// match one-for-one. Non-ASCII characters are compared exactly. `PartialEq` remains
+    /// case-sensitive.
+    pub fn eq_ignore_case(&self, other: &Arn) -> bool {
+        self.scheme
+            .as_str()
+            .eq_ignore_ascii_case(other.scheme.as_str())
+            && self
+                .domain
+                .as_str()
+                .eq_ignore_ascii_case(other.domain.as_str())
+            && self
+                .category
+                .as_str()
+                .eq_ignore_ascii_case(other.category.as_str())
+            && self
+                .account
+                .as_str()
+                .eq_ignore_ascii_case(other.account.as_str())
+            && self.root.as_str().eq_ignore_ascii_case(other.root.as_str())
+            && self.parts.0.len() == other.parts.0.len()
+            && self
+                .parts
+                .0
+                .iter()
+                .zip(other.parts.0.iter())
+                .all(|(a, b)| a.as_str().eq_ignore_ascii_case(b.as_str()))
+    }
+
+    /// Compares two Arns as if the account were a wildcard, for matching the same logical
+    /// resource across accounts.
+    ///
+    /// The scheme, domain, category, root, and parts must all be equal, exactly as `PartialEq`
+    /// compares them; only the account is skipped.
+    pub fn eq_ignoring_account(&self, other: &Arn) -> bool {
+        self.scheme == other.scheme
+            && self.domain == other.domain
             && self.category == other.category
-            && self.account == other.account
             && self.root == other.root
-            && other.parts.0.len() < self.parts.0.len()
-            && self.parts.0.starts_with(&other.parts.0)
+            && self.parts == other.parts
+            && self.part_separator == other.part_separator
     }
 
+    /// Returns the longest common ancestor of two Arns, like a filesystem common path.
+    ///
+    /// The result keeps the shared scheme, domain, category, account, and root, followed by the
+    /// longest run of leading parts the two Arns have in common, so it equals either Arn when
+    /// that Arn is an ancestor of, or identical to, the other. Returns `None` when any of the
+    /// base components differ.
+    pub fn common_prefix(&self, other: &Arn) -> Option<Arn<'a>> {
+        if self.scheme != other.scheme
+            || self.domain != other.domain
+            || self.category != other.category
+            || self.account != other.account
+            || self.root != other.root
+        {
+            return None;
+        }
+        let shared = self
+            .parts
+            .iter()
+            .zip(other.parts.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        Some(Arn {
+            domain: self.domain.clone(),
+            category: self.category.clone(),
+            account: self.account.clone(),
+            root: self.root.clone(),
+            parts: self.parts.0[..shared].iter().cloned().collect(),
+            scheme: self.scheme.clone(),
+            part_separator: self.part_separator,
+            original: None,
+        })
+    }
+
+    /// Returns the parts of `self` below `base`, the inverse of appending them with `append_part`.
+    ///
+    /// For base `arn:akton:hr:acct:root/teamA` and `self` `arn:akton:hr:acct:root/teamA/team1/member`
+    /// this yields `["team1", "member"]`. An Arn equal to `base` yields no parts, and `None` is
+    /// returned when `base` is neither `self` nor one of its ancestors.
+    ///
+    /// Like `is_descendant_of`, this compares the components and ignores the part separator.
+    pub fn strip_prefix(&self, base: &Arn) -> Option<Vec<&Part<'a>>> {
+        if !self.is_or_descends_from(base) {
+            return None;
+        }
+        Some(self.parts.0[base.parts.0.len()..].iter().collect())
+    }
+
+    /// Returns true when `self` sits strictly above `other` in the hierarchy.
+    ///
+    /// Both Arns must share the scheme, domain, category, account, and root, and the parts of
+    /// `self` must be a strict prefix of the parts of `other`. An Arn is never its own ancestor.
+    pub fn is_ancestor_of(&self, other: &Arn) -> bool {
+        other.is_descendant_of(self)
+    }
+
+    /// Returns true when `self` sits strictly below `other` in the hierarchy, at any depth.
+    ///
+    /// This is the inverse of `is_ancestor_of`; an Arn is never its own descendant.
+    pub fn is_descendant_of(&self, other: &Arn) -> bool {
+        self.is_child_of(other)
+    }
+
+    pub fn is_child_of(&self, other: &Arn) -> bool {
+        other.parts.0.len() < self.parts.0.len() && self.is_or_descends_from(other)
+    }
+
+    /// Returns true when `self` shares every component with `base` and its parts start with the
+    /// parts of `base`, whatever the part separators.
+    fn is_or_descends_from(&self, base: &Arn) -> bool {
+        self.scheme == base.scheme
+            && self.domain == base.domain
+            && self.category == base.category
+            && self.account == base.account
+            && self.root == base.root
+            && self.parts.0.starts_with(&base.parts.0)
+    }
+
+    /// Returns the Arn one level up the hierarchy, with its last part removed.
+    ///
+    /// Returns `None` when the Arn is already at its root and has no parts.
     pub fn parent(&self) -> Option<Self> {
         if self.parts.0.is_empty() {
             None
@@ -163,12 +1001,112 @@ impl<'a> Arn<'a> {
                 category: self.category.clone(),
                 account: self.account.clone(),
                 root: self.root.clone(),
-                parts: Parts(self.parts.0[..self.parts.0.len() - 1].to_vec()),
+                parts: self.parts.0[..self.parts.0.len() - 1]
+                    .iter()
+                    .cloned()
+                    .collect(),
+                scheme: self.scheme.clone(),
+                part_separator: self.part_separator,
+                original: None,
             })
         }
     }
+
+    /// Returns the peer resource with the same parent and `name` as its last part, e.g.
+    /// `root/teamA/member2` from `root/teamA/member1`.
+    ///
+    /// The new segment is validated as for `replace_part`. An Arn without parts has no parent
+    /// to share and fails with `ArnError::MissingPart`.
+    pub fn sibling(&self, name: impl Into<Cow<'a, str>>) -> Result<Arn<'a>, ArnError> {
+        match self.parts.0.len() {
+            0 => Err(ArnError::MissingPart("part".to_string())),
+            len => self.clone().replace_part(len - 1, name),
+        }
+    }
+}
+
+/// Parses an Arn string via `ArnParser`, so `let arn: Arn = s.parse()?` works.
+///
+/// `FromStr` cannot borrow from its input, so the resulting `Arn<'static>` owns every
+/// component. Use `ArnParser` directly when the parsed Arn should borrow from the input instead.
+impl FromStr for Arn<'static> {
+    type Err = ArnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ArnParser::new(s.to_owned()).parse()
+    }
+}
+
+/// Parses an Arn whose components borrow from the input string without copying them.
+impl<'a> TryFrom<&'a str> for Arn<'a> {
+    type Error = ArnError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        ArnParser::parse_borrowed(value)
+    }
+}
+
+/// Parses an Arn that takes ownership of the input string's components.
+impl TryFrom<String> for Arn<'static> {
+    type Error = ArnError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        ArnParser::new(value).parse()
+    }
+}
+
+/// Compares against the text `Display` writes, without allocating it: the original input kept
+/// with `ArnParser::keep_original` if there is one, and the canonical form otherwise. So
+/// `arn == arn.to_string().as_str()` always holds; use `Arn::equals_str` to compare against the
+/// canonical form regardless.
+impl PartialEq<str> for Arn<'_> {
+    fn eq(&self, other: &str) -> bool {
+        match self.original.as_deref() {
+            Some(original) => original == other,
+            None => self.canonical_eq(other),
+        }
+    }
+}
+
+/// Compares like `PartialEq<str>`, so `assert_eq!(arn, "arn:...")` works.
+impl PartialEq<&str> for Arn<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// Renders the Arn like `to_string`, i.e. its canonical form or its kept original input.
+impl<'a> From<Arn<'a>> for String {
+    fn from(arn: Arn<'a>) -> Self {
+        arn.to_string()
+    }
+}
+
+/// Renders the Arn like `to_string`.
+///
+/// An original input kept with `ArnParser::keep_original` is exactly what `to_string` renders,
+/// so it is borrowed. Otherwise the components are stored separately rather than as one string,
+/// and the canonical text is assembled into a `Cow::Owned`.
+impl<'a> From<&'a Arn<'a>> for Cow<'a, str> {
+    fn from(arn: &'a Arn<'a>) -> Self {
+        match arn.original.as_deref() {
+            Some(original) => Cow::Borrowed(original),
+            None => Cow::Owned(arn.to_string()),
+        }
+    }
+}
+
+/// Iterates over the parts of the Arn, so `for part in &arn` walks the resource path.
+impl<'p, 'a> IntoIterator for &'p Arn<'a> {
+    type Item = &'p Part<'a>;
+    type IntoIter = core::slice::Iter<'p, Part<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.parts.iter()
+    }
 }
 
+#[cfg(feature = "std")]
 impl<'a> Default for Arn<'a> {
     /// Provides a default value for Arn using the defaults of all its components.
     fn default() -> Self {
@@ -178,6 +1116,9 @@ impl<'a> Default for Arn<'a> {
             account: Account::default(),
             root: Root::default(),
             parts: Parts::new(Vec::default()),
+            scheme: Scheme::default(),
+            part_separator: DEFAULT_PART_SEPARATOR,
+            original: None,
         }
     }
 }
@@ -186,12 +1127,13 @@ impl<'a> Default for Arn<'a> {
 mod tests {
     use super::*;
     use crate::Part;
-    use std::str::FromStr;
+    use alloc::{format, vec};
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_arn_with_root() {
         let arn = Arn::with_root("custom_root").unwrap();
-        assert!(arn.root.as_str().starts_with("custom_root"));
+        assert_eq!(arn.root.as_str(), "custom_root");
         assert_eq!(arn.domain, Domain::default());
         assert_eq!(arn.category, Category::default());
         assert_eq!(arn.account, Account::default());
@@ -199,10 +1141,11 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_arn_with_new_root() {
         let original_arn = Arn::default();
         let new_arn = original_arn.with_new_root("new_root").unwrap();
-        assert!(new_arn.root.as_str().starts_with("new_root"));
+        assert!(new_arn.root.as_str().starts_with("new_root_"));
         assert_eq!(new_arn.domain, original_arn.domain);
         assert_eq!(new_arn.category, original_arn.category);
         assert_eq!(new_arn.account, original_arn.account);
@@ -210,67 +1153,253 @@ mod tests {
     }
 
     #[test]
-    fn test_add_arns() -> anyhow::Result<()> {
-        let parent_root = Root::from_str("root_a")?;
-        let parent = Arn::new(
-            Domain::from_str("akton-internal").unwrap(),
-            Category::from_str("hr").unwrap(),
-            Account::from_str("company123").unwrap(),
-            parent_root.clone(),
-            Parts(vec![
-                Part::from_str("department_a").unwrap(),
-                Part::from_str("team1").unwrap(),
-            ]),
-        );
+    fn test_arn_builder_entry_point() -> anyhow::Result<()> {
+        let arn = Arn::builder()
+            .with::<Domain>("akton")?
+            .with::<Category>("hr")?
+            .with::<Account>("acct")?
+            .with::<Root>("root")?
+            .with::<Part>("teamA")?
+            .build()?;
+        assert_eq!(arn.domain(), "akton");
+        assert_eq!(arn.parts().to_string(), "teamA");
+        Ok(())
+    }
 
-        let child = Arn::new(
-            Domain::from_str("akton-internal").unwrap(),
-            Category::from_str("hr").unwrap(),
-            Account::from_str("company123").unwrap(),
-            Root::from_str("root_b").unwrap(),
-            Parts(vec![Part::from_str("role_x").unwrap()]),
+    #[test]
+    fn test_display_alternate_labels_components() -> anyhow::Result<()> {
+        let arn = ArnParser::new("arn:akton:hr:acct:root/teamA/team1").parse()?;
+        assert_eq!(format!("{}", arn), "arn:akton:hr:acct:root/teamA/team1");
+        assert_eq!(
+            format!("{:#}", arn),
+            "domain=akton category=hr account=acct root=root parts=[teamA, team1]"
         );
-
-        let combined = parent + child;
-
-        assert_eq!(combined.domain, Domain::from_str("akton-internal").unwrap());
-        assert_eq!(combined.category, Category::from_str("hr").unwrap());
-        assert_eq!(combined.account, Account::from_str("company123").unwrap());
-        assert_eq!(combined.root, parent_root);
+        let bare = ArnParser::new("arn:akton:hr:acct:root").parse()?;
         assert_eq!(
-            combined.parts,
-            Parts(vec![
-                Part::from_str("department_a").unwrap(),
-                Part::from_str("team1").unwrap(),
-                Part::from_str("role_x").unwrap(),
-            ])
+            format!("{:#}", bare),
+            "domain=akton category=hr account=acct root=root parts=[]"
         );
         Ok(())
     }
 
     #[test]
-    fn test_add_arns_empty_child() {
-        let parent = Arn::new(
-            Domain::from_str("akton-internal").unwrap(),
-            Category::from_str("hr").unwrap(),
-            Account::from_str("company123").unwrap(),
-            Root::from_str("rootp").unwrap(),
-            Parts(vec![Part::from_str("department_a").unwrap()]),
-        );
+    fn test_redact_account() -> anyhow::Result<()> {
+        let arn = ArnParser::new("arn:akton:hr:company123:root/teamA").parse()?;
+        let redacted = arn.redact_account();
+        assert_eq!(redacted, "arn:akton:hr:****:root/teamA");
+        assert!(!redacted.contains("company123"));
+        assert_eq!(arn.account(), "company123");
+        Ok(())
+    }
 
-        let child = Arn::new(
-            Domain::from_str("akton-internal").unwrap(),
+    #[test]
+    fn test_normalize_equivalent_arns() -> anyhow::Result<()> {
+        let strict = ArnParser::new("arn:akton:hr:acct:root/teamA/50%off").parse()?;
+        let sloppy = ArnParser::new(" ARN:akton:hr:acct:root//teamA/50%off/ ")
+            .lenient()
+            .keep_original()
+            .parse()?;
+        assert_ne!(strict.to_string(), sloppy.to_string());
+
+        let normalized = strict.normalize().to_string();
+        assert_eq!(normalized, "arn:akton:hr:acct:root/teamA/50%off");
+        assert_eq!(sloppy.normalize().to_string(), normalized);
+        let again = ArnParser::new(normalized.as_str()).parse()?.normalize();
+        assert_eq!(again.to_string(), normalized);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_into_string_and_cow() -> anyhow::Result<()> {
+        let input = "arn:akton:hr:acct:root/teamA";
+        let arn = Arn::try_from(input)?;
+        let cow: Cow<str> = (&arn).into();
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!(cow, input);
+
+        let text: String = arn.into();
+        assert_eq!(text, input);
+
+        let kept = ArnParser::new(input).keep_original().parse()?;
+        let cow: Cow<str> = (&kept).into();
+        assert!(matches!(cow, Cow::Borrowed(text) if text == input));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_eq_str() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA")?;
+        assert_eq!(arn, "arn:akton:hr:acct:root/teamA");
+        assert!(arn == *"arn:akton:hr:acct:root/teamA");
+        assert_ne!(arn, "arn:akton:hr:acct:root/teamB");
+        assert_ne!(arn, "arn:akton:hr:acct:root/teamA/member1");
+        assert_ne!(arn, "arn:akton:hr:acct:root");
+        assert_ne!(arn, "");
+        Ok(())
+    }
+
+    #[test]
+    fn test_strip_prefix() -> anyhow::Result<()> {
+        let base = Arn::try_from("arn:akton:hr:acct:root/teamA")?;
+        let resource = Arn::try_from("arn:akton:hr:acct:root/teamA/team1/member")?;
+        let relative: Vec<&str> = resource
+            .strip_prefix(&base)
+            .expect("descendant")
+            .into_iter()
+            .map(Part::as_str)
+            .collect();
+        assert_eq!(relative, ["team1", "member"]);
+
+        assert_eq!(base.strip_prefix(&base), Some(Vec::new()));
+        assert_eq!(base.strip_prefix(&resource), None);
+        let other = Arn::try_from("arn:akton:hr:acct:root/teamB/team1/member")?;
+        assert_eq!(other.strip_prefix(&base), None);
+        let sibling = Arn::try_from("arn:akton:hr:acct:root/teamB")?;
+        assert_eq!(sibling.strip_prefix(&base), None);
+
+        // The part separator is not part of the hierarchy
+        let dotted = |input| {
+            ArnParser::new(input)
+                .with_part_separator('.')
+                .and_then(|parser| parser.parse())
+        };
+        let dotted_base = dotted("arn:akton:hr:acct:root.teamA")?;
+        assert!(resource.is_descendant_of(&dotted_base));
+        assert_eq!(
+            resource.strip_prefix(&dotted_base).map(|p| p.len()),
+            Some(2)
+        );
+        assert_eq!(base.strip_prefix(&dotted_base), Some(Vec::new()));
+        let dotted_resource = dotted("arn:akton:hr:acct:root.teamA.team1")?;
+        assert_eq!(
+            dotted_resource.strip_prefix(&base).map(|p| p.len()),
+            Some(1)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_eq_ignoring_account() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct1:root/teamA")?;
+        let other_account = Arn::try_from("arn:akton:hr:acct2:root/teamA")?;
+        assert_ne!(arn, other_account);
+        assert!(arn.eq_ignoring_account(&other_account));
+        assert!(arn.eq_ignoring_account(&arn));
+        for different in [
+            "arn:akton:service:acct2:root/teamA",
+            "arn:other:hr:acct2:root/teamA",
+            "arn:akton:hr:acct2:root/teamB",
+            "arn:akton:hr:acct2:root",
+            "qrn:akton:hr:acct2:root/teamA",
+        ] {
+            let different = Arn::try_from(different)
+                .or_else(|_| ArnParser::new(different).with_scheme("qrn")?.parse())?;
+            assert!(!arn.eq_ignoring_account(&different), "{different}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_parts() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/TeamA/Member1")?;
+        let lowered = arn.clone().map_parts(|part| part.to_lowercase())?;
+        assert_eq!(lowered, "arn:akton:hr:acct:root/teama/member1");
+
+        let invalid = arn.map_parts(|part| format!("{part}/x"));
+        assert_eq!(
+            invalid,
+            Err(ArnError::InvalidPart {
+                index: 0,
+                value: "TeamA/x".to_string()
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_parts() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/tmp/member1")?;
+        let filtered = arn.filter_parts(|part| part.as_str() != "tmp");
+        assert_eq!(filtered, "arn:akton:hr:acct:root/teamA/member1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_part() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/team1/member")?;
+        let renamed = arn.clone().replace_part(1, "team2")?;
+        assert_eq!(renamed, "arn:akton:hr:acct:root/teamA/team2/member");
+
+        assert_eq!(
+            arn.clone().replace_part(3, "extra"),
+            Err(ArnError::PartIndexOutOfBounds { index: 3, len: 3 })
+        );
+        assert_eq!(arn.replace_part(0, "a/b"), Err(ArnError::InvalidPartFormat));
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_arns() -> anyhow::Result<()> {
+        let parent_root = Root::from_str("root_a")?;
+        let parent = Arn::new(
+            Domain::from_str("akton-internal").unwrap(),
+            Category::from_str("hr").unwrap(),
+            Account::from_str("company123").unwrap(),
+            parent_root.clone(),
+            Parts::new(vec![
+                Part::from_str("department_a").unwrap(),
+                Part::from_str("team1").unwrap(),
+            ]),
+        );
+
+        let child = Arn::new(
+            Domain::from_str("akton-internal").unwrap(),
+            Category::from_str("hr").unwrap(),
+            Account::from_str("company123").unwrap(),
+            Root::from_str("root_b").unwrap(),
+            Parts::new(vec![Part::from_str("role_x").unwrap()]),
+        );
+
+        let combined = parent + child;
+
+        assert_eq!(combined.domain, Domain::from_str("akton-internal").unwrap());
+        assert_eq!(combined.category, Category::from_str("hr").unwrap());
+        assert_eq!(combined.account, Account::from_str("company123").unwrap());
+        assert_eq!(combined.root, parent_root);
+        assert_eq!(
+            combined.parts,
+            Parts::new(vec![
+                Part::from_str("department_a").unwrap(),
+                Part::from_str("team1").unwrap(),
+                Part::from_str("role_x").unwrap(),
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_arns_empty_child() {
+        let parent = Arn::new(
+            Domain::from_str("akton-internal").unwrap(),
+            Category::from_str("hr").unwrap(),
+            Account::from_str("company123").unwrap(),
+            Root::from_str("rootp").unwrap(),
+            Parts::new(vec![Part::from_str("department_a").unwrap()]),
+        );
+
+        let child = Arn::new(
+            Domain::from_str("akton-internal").unwrap(),
             Category::from_str("hr").unwrap(),
             Account::from_str("company123").unwrap(),
             Root::from_str("rootc").unwrap(),
-            Parts(vec![]),
+            Parts::new(vec![]),
         );
 
         let combined = parent + child;
 
         assert_eq!(
             combined.parts,
-            Parts(vec![Part::from_str("department_a").unwrap()])
+            Parts::new(vec![Part::from_str("department_a").unwrap()])
         );
     }
 
@@ -281,19 +1410,19 @@ mod tests {
             Category::from_str("hr").unwrap(),
             Account::from_str("company123").unwrap(),
             Root::from_str("rootp").unwrap(),
-            Parts(vec![]),
+            Parts::new(vec![]),
         );
         let child = Arn::new(
             Domain::from_str("akton-internal").unwrap(),
             Category::from_str("hr").unwrap(),
             Account::from_str("company123").unwrap(),
             Root::from_str("rootc").unwrap(),
-            Parts(vec![Part::from_str("role_x").unwrap()]),
+            Parts::new(vec![Part::from_str("role_x").unwrap()]),
         );
         let combined = parent + child;
         assert_eq!(
             combined.parts,
-            Parts(vec![Part::from_str("role_x").unwrap()])
+            Parts::new(vec![Part::from_str("role_x").unwrap()])
         );
     }
 
@@ -304,7 +1433,7 @@ mod tests {
             Category::from_str("hr").unwrap(),
             Account::from_str("company123").unwrap(),
             Root::from_str("rootp").unwrap(),
-            Parts(vec![Part::from_str("department_a").unwrap()]),
+            Parts::new(vec![Part::from_str("department_a").unwrap()]),
         );
 
         let child = Arn::new(
@@ -312,7 +1441,7 @@ mod tests {
             Category::from_str("hr").unwrap(),
             Account::from_str("company123").unwrap(),
             Root::from_str("rootc").unwrap(),
-            Parts(vec![Part::from_str("team1").unwrap()]),
+            Parts::new(vec![Part::from_str("team1").unwrap()]),
         );
 
         let combined = parent + child;
@@ -325,8 +1454,8 @@ mod tests {
     fn test_arn_custom() -> anyhow::Result<()> {
         let arn = Arn::new(
             Domain::new("custom")?,
-            Category::new("service"),
-            Account::new("account123"),
+            Category::new("service")?,
+            Account::new("account123")?,
             Root::new("root")?,
             Parts::new(vec![Part::new("resource")?]),
         );
@@ -336,6 +1465,788 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_arn_from_str() -> anyhow::Result<()> {
+        let arn: Arn = "arn:akton-internal:hr:company123:root/departmentA/team1".parse()?;
+        assert_eq!(arn.domain.as_str(), "akton-internal");
+        assert_eq!(arn.category.as_str(), "hr");
+        assert_eq!(arn.account.as_str(), "company123");
+        assert_eq!(arn.root.as_str(), "root");
+        assert_eq!(arn.parts.to_string(), "departmentA/team1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_from_str_outlives_input() -> anyhow::Result<()> {
+        let arn: Arn<'static> = {
+            let input = String::from("arn:custom:service:account123:root/resource");
+            input.parse()?
+        };
+        assert_eq!(
+            arn.to_string(),
+            "arn:custom:service:account123:root/resource"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_from_str_malformed() {
+        assert_eq!(
+            "invalid:arn:format".parse::<Arn>(),
+            Err(ArnError::InvalidFormat)
+        );
+        assert!("arn:domain:category:account:root/invalid:part"
+            .parse::<Arn>()
+            .is_err());
+        assert!("arn::category:account:root".parse::<Arn>().is_err());
+    }
+
+    #[test]
+    fn test_arn_try_from_str_borrows() -> anyhow::Result<()> {
+        let input = "arn:akton-internal:hr:company123:root/departmentA/team1";
+        let arn = Arn::try_from(input)?;
+        assert!(matches!(arn.domain.0, Cow::Borrowed(_)));
+        assert!(matches!(arn.category.0, Cow::Borrowed(_)));
+        assert!(matches!(arn.account.0, Cow::Borrowed(_)));
+        assert!(matches!(arn.root.0, Cow::Borrowed(_)));
+        assert!(arn.parts.0.iter().all(|p| matches!(p.0, Cow::Borrowed(_))));
+        assert_eq!(arn.to_string(), input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_try_from_string() -> anyhow::Result<()> {
+        let input = String::from("arn:custom:service:account123:root/resource");
+        let arn = Arn::try_from(input.clone())?;
+        assert!(matches!(arn.domain.0, Cow::Owned(_)));
+        assert_eq!(arn.to_string(), input);
+        assert_eq!(
+            Arn::try_from(String::from("invalid:arn:format")),
+            Err(ArnError::InvalidFormat)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_equality_borrowed_and_owned() -> anyhow::Result<()> {
+        let input = "arn:akton-internal:hr:company123:root/departmentA/team1";
+        let borrowed = Arn::try_from(input)?;
+        let owned = Arn::try_from(input.to_string())?;
+        assert!(matches!(borrowed.domain.0, Cow::Borrowed(_)));
+        assert!(matches!(owned.domain.0, Cow::Owned(_)));
+        assert_eq!(borrowed, owned);
+        assert_ne!(
+            borrowed,
+            Arn::try_from("arn:akton-internal:hr:company123:root")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_equality_built_and_parsed() -> anyhow::Result<()> {
+        let built = crate::ArnBuilder::new()
+            .with::<Domain>("akton-internal")?
+            .with::<Category>("hr")?
+            .with::<Account>("company123")?
+            .with::<Root>("root")?
+            .with::<Part>("departmentA")?
+            .build()?;
+        let text = built.to_string();
+        let parsed = ArnParser::new(text.as_str()).parse()?;
+        assert_eq!(built, parsed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_scheme_accessor() -> anyhow::Result<()> {
+        let arn = Arn::new(
+            Domain::new("akton")?,
+            Category::new("hr")?,
+            Account::new("acct")?,
+            Root(Cow::Borrowed("root")),
+            Parts::new(vec![]),
+        );
+        assert_eq!(arn.scheme(), "arn");
+
+        let qrn = crate::QrnParser::new("qrn:akton:hr:acct:root/teamA").parse()?;
+        assert_eq!(qrn.scheme(), "qrn");
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_accessors_borrow_input() -> anyhow::Result<()> {
+        let input = "arn:akton-internal:hr:company123:root/departmentA";
+        let arn = Arn::try_from(input)?;
+        assert_eq!(arn.scheme(), "arn");
+        assert_eq!(arn.domain(), "akton-internal");
+        assert_eq!(arn.category(), "hr");
+        assert_eq!(arn.account(), "company123");
+        assert_eq!(arn.root(), "root");
+
+        // The slices point into the original input rather than a copy
+        assert!(core::ptr::eq(arn.domain(), &input[4..18]));
+        assert!(core::ptr::eq(arn.root(), &input[33..37]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_into_owned_outlives_buffer() -> anyhow::Result<()> {
+        let owned: Arn<'static> = {
+            let buffer = String::from("arn:akton-internal:hr:company123:root/departmentA");
+            let borrowed = Arn::try_from(buffer.as_str())?;
+            borrowed.into_owned()
+        };
+        assert_eq!(owned.domain(), "akton-internal");
+        assert_eq!(
+            owned.to_string(),
+            "arn:akton-internal:hr:company123:root/departmentA"
+        );
+        assert!(matches!(owned.domain.0, Cow::Owned(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_ancestor_and_descendant() -> anyhow::Result<()> {
+        let root = Arn::try_from("arn:akton:hr:acct:root")?;
+        let team = Arn::try_from("arn:akton:hr:acct:root/teamA")?;
+        let member = Arn::try_from("arn:akton:hr:acct:root/teamA/member1")?;
+        let other = Arn::try_from("arn:akton:hr:acct:root/teamB/member1")?;
+        let other_account = Arn::try_from("arn:akton:hr:other:root/teamA/member1")?;
+
+        assert!(root.is_ancestor_of(&member));
+        assert!(team.is_ancestor_of(&member));
+        assert!(member.is_descendant_of(&root));
+        assert!(member.is_descendant_of(&team));
+
+        // Same path
+        assert!(!team.is_ancestor_of(&team));
+        assert!(!team.is_descendant_of(&team));
+
+        // Divergent paths and bases
+        assert!(!team.is_ancestor_of(&other));
+        assert!(!other.is_descendant_of(&team));
+        assert!(!team.is_ancestor_of(&other_account));
+        assert!(!member.is_ancestor_of(&team));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_common_prefix() -> anyhow::Result<()> {
+        let member = Arn::try_from("arn:akton:hr:acct:root/teamA/member1/widget")?;
+        for (other, expected) in [
+            (
+                "arn:akton:hr:acct:root/teamA/member1/gadget",
+                "arn:akton:hr:acct:root/teamA/member1",
+            ),
+            (
+                "arn:akton:hr:acct:root/teamA/member2",
+                "arn:akton:hr:acct:root/teamA",
+            ),
+            ("arn:akton:hr:acct:root/teamB", "arn:akton:hr:acct:root"),
+            ("arn:akton:hr:acct:root", "arn:akton:hr:acct:root"),
+            (
+                "arn:akton:hr:acct:root/teamA/member1/widget",
+                "arn:akton:hr:acct:root/teamA/member1/widget",
+            ),
+        ] {
+            let common = member.common_prefix(&Arn::try_from(other)?);
+            assert_eq!(common, Some(Arn::try_from(expected)?), "{other}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_common_prefix_different_bases() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA")?;
+        for other in [
+            "arn:other:hr:acct:root/teamA",
+            "arn:akton:it:acct:root/teamA",
+            "arn:akton:hr:acct2:root/teamA",
+            "arn:akton:hr:acct:root2/teamA",
+        ] {
+            assert_eq!(arn.common_prefix(&Arn::try_from(other)?), None, "{other}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_replace_account() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:company123:root/departmentA")?;
+        let moved = arn.clone().replace_account("company456")?;
+        assert_eq!(moved.account(), "company456");
+        assert_eq!(moved.domain, arn.domain);
+        assert_eq!(moved.category, arn.category);
+        assert_eq!(moved.root, arn.root);
+        assert_eq!(moved.parts, arn.parts);
+        assert_eq!(Arn::try_from(moved.to_string())?, moved);
+
+        assert!(arn.replace_account("bad/account").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_replace_category_and_scheme() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA")?;
+        let moved = arn.clone().replace_category("it")?.replace_scheme("qrn")?;
+        assert_eq!(moved.to_string(), "qrn:akton:it:acct:root/teamA");
+        assert_eq!(moved.parts(), arn.parts());
+
+        assert!(arn.clone().replace_category("i:t").is_err());
+        assert!(arn.replace_scheme("Q").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_replace_domain() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton-internal:hr:company123:root/departmentA")?;
+        let external = arn.clone().replace_domain("akton")?;
+        assert_eq!(
+            external.to_string(),
+            "arn:akton:hr:company123:root/departmentA"
+        );
+        assert_eq!(external.account, arn.account);
+        assert_eq!(external.parts, arn.parts);
+
+        assert!(arn.replace_domain("Akton").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_replace_root() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:company123:root/departmentA/team1")?;
+        let replacement = Root::new("promoted_01h455vb4pex5vsknk084sn02q")?;
+        let promoted = arn.clone().replace_root(replacement.as_str())?;
+        assert_eq!(promoted.root, replacement);
+        assert_eq!(promoted.domain, arn.domain);
+        assert_eq!(promoted.category, arn.category);
+        assert_eq!(promoted.account, arn.account);
+        assert_eq!(promoted.parts, arn.parts);
+        assert_eq!(promoted.scheme, arn.scheme);
+        assert_eq!(Arn::try_from(promoted.to_string())?, promoted);
+
+        assert_eq!(
+            arn.clone()
+                .replace_root("root_01h455vb4pex5vsknk084sn02q")?
+                .root(),
+            "root_01h455vb4pex5vsknk084sn02q"
+        );
+        assert_eq!(
+            arn.clone().replace_root("ro/ot"),
+            Err(ArnError::InvalidValue("Root", "ro/ot".to_string()))
+        );
+        assert!(arn.replace_root("").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_depth_and_trim() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/member1/widget")?;
+        assert_eq!(arn.depth(), 3);
+        assert_eq!(Arn::try_from("arn:akton:hr:acct:root")?.depth(), 0);
+
+        for (n, expected) in [
+            (0, "arn:akton:hr:acct:root"),
+            (1, "arn:akton:hr:acct:root/teamA"),
+            (2, "arn:akton:hr:acct:root/teamA/member1"),
+            (3, "arn:akton:hr:acct:root/teamA/member1/widget"),
+            (10, "arn:akton:hr:acct:root/teamA/member1/widget"),
+        ] {
+            let trimmed = arn.clone().trim_to_depth(n);
+            assert_eq!(trimmed.to_string(), expected);
+            assert_eq!(trimmed.depth(), n.min(3));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_arn_to_path() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/team1")?;
+        assert_eq!(
+            arn.to_path(),
+            std::path::Path::new("root").join("teamA").join("team1")
+        );
+        assert_eq!(arn.parts_path(), "teamA/team1");
+
+        let root = Arn::try_from("arn:akton:hr:acct:root")?;
+        assert_eq!(root.to_path(), std::path::PathBuf::from("root"));
+        assert_eq!(root.parts_path(), "");
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_arn_format() -> anyhow::Result<()> {
+        let vars = std::collections::HashMap::from([("account", "acct"), ("team", "teamA")]);
+        let arn = Arn::format("arn:akton:hr:{account}:root/{team}", &vars)?;
+        assert_eq!(arn, Arn::try_from("arn:akton:hr:acct:root/teamA")?);
+
+        assert_eq!(
+            Arn::format("arn:akton:hr:{account}:root/{member}", &vars),
+            Err(ArnError::MissingPlaceholder("member".to_string()))
+        );
+        assert_eq!(
+            Arn::format("arn:akton:hr:{account:root", &vars),
+            Err(ArnError::InvalidCharacter {
+                found: '{',
+                position: 13
+            })
+        );
+        assert!(matches!(
+            Arn::format("arn:akton:hr:{account}:{team}:root", &vars),
+            Err(ArnError::InvalidCharacter { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_root_path() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/team1")?;
+        assert_eq!(arn.root_path(), "root/teamA/team1");
+        assert_eq!(Arn::try_from("arn:akton:hr:acct:root")?.root_path(), "root");
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_contains_part() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/member1")?;
+        assert!(arn.contains_part("teamA"));
+        assert!(arn.contains_part("member1"));
+        assert!(!arn.contains_part("root"));
+        assert!(!Arn::try_from("arn:akton:hr:acct:root")?.contains_part("teamA"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_segments() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/member1")?;
+        let segments: Vec<&str> = arn.segments().collect();
+        assert_eq!(
+            segments,
+            vec!["akton", "hr", "acct", "root", "teamA", "member1"]
+        );
+        assert_eq!(
+            Arn::try_from("arn:akton:hr:acct:root")?.segments().count(),
+            4
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_diff_single_component() -> anyhow::Result<()> {
+        let desired = Arn::try_from("arn:akton:hr:acct:root/teamA")?;
+        let actual = Arn::try_from("arn:akton:hr:other:root/teamA")?;
+        let diff = desired.diff(&actual);
+        assert_eq!(
+            diff.account,
+            crate::ComponentChange::Changed {
+                from: "acct",
+                to: "other"
+            }
+        );
+        assert_eq!(diff.domain, crate::ComponentChange::Same);
+        assert_eq!(diff.parts, vec![crate::PartChange::Same("teamA")]);
+        assert!(!diff.is_same());
+        assert_eq!(diff.to_string(), "account: acct -> other");
+
+        assert!(desired.diff(&desired).is_same());
+        assert_eq!(desired.diff(&desired).to_string(), "no differences");
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_diff_parts() -> anyhow::Result<()> {
+        use crate::PartChange;
+
+        let desired = Arn::try_from("arn:akton:hr:acct:root/teamA/member1")?;
+        let actual = Arn::try_from("arn:akton:hr:acct:root/teamB/member1/widget")?;
+        let diff = desired.diff(&actual);
+        assert_eq!(
+            diff.parts,
+            vec![
+                PartChange::Changed {
+                    from: "teamA",
+                    to: "teamB"
+                },
+                PartChange::Same("member1"),
+                PartChange::Added("widget"),
+            ]
+        );
+        assert_eq!(
+            diff.to_string(),
+            "part 0: teamA -> teamB\npart 2 added: widget"
+        );
+
+        let removed = actual.diff(&desired);
+        assert_eq!(removed.parts[2], PartChange::Removed("widget"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_eq_ignore_case() -> anyhow::Result<()> {
+        let lower = Arn::try_from("arn:akton:hr:company123:root/departmenta/team1")?;
+        let mut mixed = Arn::try_from("arn:akton:HR:Company123:Root/DepartmentA/TEAM1")?;
+        mixed.scheme = Scheme(Cow::Borrowed("ARN"));
+        mixed.domain = Domain(Cow::Borrowed("Akton"));
+
+        assert!(mixed.eq_ignore_case(&lower));
+        assert!(lower.eq_ignore_case(&mixed));
+        assert_ne!(mixed, lower);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_eq_ignore_case_differences() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:company123:root/departmentA")?;
+        for other in [
+            "arn:akton:hr:company124:root/departmentA",
+            "arn:akton:hr:company123:root/departmentB",
+            "arn:akton:hr:company123:root/departmentA/team1",
+            "arn:akton:hr:company123:root",
+        ] {
+            assert!(!arn.eq_ignore_case(&Arn::try_from(other)?), "{other}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_arn_as_hash_map_key() -> anyhow::Result<()> {
+        use std::collections::HashMap;
+
+        let input = "arn:akton-internal:hr:company123:root/departmentA/team1";
+        let mut map = HashMap::new();
+        map.insert(Arn::try_from(input.to_string())?, "team1");
+
+        let borrowed = Arn::try_from(input)?;
+        assert_eq!(map.get(&borrowed), Some(&"team1"));
+        assert_eq!(map.get(&borrowed.parent().unwrap()), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_sorting() -> anyhow::Result<()> {
+        let mut arns: Vec<Arn> = vec![
+            "arn:akton:hr:acct:root/teamB".parse()?,
+            "arn:akton:hr:acct:root/teamA/member2".parse()?,
+            "arn:akton:billing:acct:root/teamZ".parse()?,
+            "arn:akton:hr:acct:root".parse()?,
+            "arn:akton:hr:acct:root/teamA".parse()?,
+            "arn:akton:hr:acct:root/teamA/member1".parse()?,
+        ];
+        arns.sort();
+
+        let sorted: Vec<String> = arns.iter().map(Arn::to_string).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                "arn:akton:billing:acct:root/teamZ",
+                "arn:akton:hr:acct:root",
+                "arn:akton:hr:acct:root/teamA",
+                "arn:akton:hr:acct:root/teamA/member1",
+                "arn:akton:hr:acct:root/teamA/member2",
+                "arn:akton:hr:acct:root/teamB",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_iterate_parts() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/member1")?;
+        let parts: Vec<&str> = (&arn).into_iter().map(Part::as_str).collect();
+        assert_eq!(parts, vec!["teamA", "member1"]);
+
+        let root_only = Arn::try_from("arn:akton:hr:acct:root")?;
+        assert_eq!((&root_only).into_iter().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_append_part() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA")?.append_part("member1")?;
+        assert_eq!(arn.to_string(), "arn:akton:hr:acct:root/teamA/member1");
+
+        let arn = Arn::try_from("arn:akton:hr:acct:root")?.append_part("teamA")?;
+        assert_eq!(arn.to_string(), "arn:akton:hr:acct:root/teamA");
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_append_part_rejects_separators() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA")?;
+        assert_eq!(
+            arn.clone().append_part("member/1"),
+            Err(ArnError::InvalidPartFormat)
+        );
+        assert_eq!(
+            arn.append_part("member:1"),
+            Err(ArnError::InvalidPartFormat)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_pop_part() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/member1")?;
+
+        let (arn, popped) = arn.pop_part();
+        assert_eq!(popped, Some(Part::new("member1")?));
+        assert_eq!(arn.to_string(), "arn:akton:hr:acct:root/teamA");
+
+        let (arn, popped) = arn.pop_part();
+        assert_eq!(popped, Some(Part::new("teamA")?));
+        assert_eq!(arn.to_string(), "arn:akton:hr:acct:root");
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_pop_part_empty() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root")?;
+        let (popped_arn, popped) = arn.clone().pop_part();
+        assert_eq!(popped, None);
+        assert_eq!(popped_arn, arn);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_keeps_original_input() -> anyhow::Result<()> {
+        let input = "ARN:akton:hr:acct:root/teamA";
+        let arn = ArnParser::new(input).keep_original().parse()?;
+        assert_eq!(arn.original(), Some(input));
+        assert_eq!(arn.to_string(), input);
+        assert_eq!(arn.into_owned().to_string(), input);
+
+        let lenient = ArnParser::new(" arn:akton:hr:acct:root//teamA/ ")
+            .lenient()
+            .keep_original()
+            .parse()?;
+        assert_eq!(lenient.to_string(), " arn:akton:hr:acct:root//teamA/ ");
+        // Comparisons with Arns ignore the original and use the components, while comparisons
+        // with strings agree with `Display`
+        let canonical = Arn::try_from("arn:akton:hr:acct:root/teamA")?;
+        assert_eq!(lenient, canonical);
+        assert_eq!(lenient, lenient.to_string().as_str());
+        assert_ne!(lenient, "arn:akton:hr:acct:root/teamA");
+        assert!(lenient.equals_str("arn:akton:hr:acct:root/teamA"));
+        assert_eq!(canonical.original(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_modification_drops_original() -> anyhow::Result<()> {
+        let parse = || {
+            ArnParser::new("ARN:akton:hr:acct:root/teamA")
+                .keep_original()
+                .parse()
+        };
+        let appended = parse()?.append_part("member1")?;
+        assert_eq!(appended.original(), None);
+        assert_eq!(appended.to_string(), "arn:akton:hr:acct:root/teamA/member1");
+
+        let replaced = parse()?.replace_account("other")?;
+        assert_eq!(replaced.to_string(), "arn:akton:hr:other:root/teamA");
+        assert_eq!(parse()?.redact_account(), "arn:akton:hr:****:root/teamA");
+        assert_eq!(parse()?.pop_part().0.to_string(), "arn:akton:hr:acct:root");
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_to_columns() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/team1")?;
+        assert_eq!(
+            arn.to_columns(),
+            ["akton", "hr", "acct", "root", "teamA/team1"]
+        );
+        let root_only = Arn::try_from("arn:akton::acct:root")?;
+        assert_eq!(root_only.to_columns(), ["akton", "", "acct", "root", ""]);
+
+        let row = arn
+            .to_columns()
+            .iter()
+            .map(|column| format!("{column:<6}"))
+            .collect::<Vec<_>>()
+            .join("|");
+        assert_eq!(row, "akton |hr    |acct  |root  |teamA/team1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_display_honors_width() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root")?;
+        assert_eq!(format!("{arn:>24}"), "  arn:akton:hr:acct:root");
+        assert_eq!(format!("{arn:-<24}|"), "arn:akton:hr:acct:root--|");
+        assert_eq!(format!("{arn:4}"), "arn:akton:hr:acct:root");
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_try_new() -> anyhow::Result<()> {
+        let arn = Arn::try_new("akton", "hr", "acct", "root", ["teamA", "team1"])?;
+        assert_eq!(arn, Arn::try_from("arn:akton:hr:acct:root/teamA/team1")?);
+        let no_parts: [&str; 0] = [];
+        assert_eq!(
+            Arn::try_new("akton", "hr", "acct", "root", no_parts)?.to_string(),
+            "arn:akton:hr:acct:root"
+        );
+
+        assert_eq!(
+            Arn::try_new("Akton", "hr", "acct", "root", no_parts),
+            Err(ArnError::InvalidValue("Domain", "Akton".to_string()))
+        );
+        assert_eq!(
+            Arn::try_new("akton", "h:r", "acct", "root", no_parts),
+            Err(ArnError::InvalidValue("Category", "h:r".to_string()))
+        );
+        assert!(matches!(
+            Arn::try_new("akton", "hr", "", "root", no_parts),
+            Err(ArnError::ParseFailure("Account", _))
+        ));
+        assert_eq!(
+            Arn::try_new("akton", "hr", "acct", "ro/ot", no_parts),
+            Err(ArnError::InvalidValue("Root", "ro/ot".to_string()))
+        );
+        assert_eq!(
+            Arn::try_new("akton", "hr", "acct", "", no_parts),
+            Err(ArnError::ParseFailure(
+                "Root",
+                "cannot be empty".to_string()
+            ))
+        );
+        assert_eq!(
+            Arn::try_new("akton", "hr", "acct", "r !", ["teamA"]),
+            Err(ArnError::InvalidValue("Root", "r !".to_string()))
+        );
+        assert_eq!(
+            Arn::try_new("akton", "hr", "acct", "root", ["teamA", "a:b"]),
+            Err(ArnError::InvalidPart {
+                index: 1,
+                value: "a:b".to_string()
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_sibling() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/member1")?;
+        let sibling = arn.sibling("member2")?;
+        assert_eq!(sibling.to_string(), "arn:akton:hr:acct:root/teamA/member2");
+        assert_eq!(sibling.parent(), arn.parent());
+        assert_eq!(arn.sibling("a/b"), Err(ArnError::InvalidPartFormat));
+
+        let root_only = Arn::try_from("arn:akton:hr:acct:root")?;
+        assert_eq!(
+            root_only.sibling("member2"),
+            Err(ArnError::MissingPart("part".to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_parent_walks_to_root() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/region1/building5/floor3")?;
+        let expected = [
+            "arn:akton:hr:acct:root/region1/building5",
+            "arn:akton:hr:acct:root/region1",
+            "arn:akton:hr:acct:root",
+        ];
+
+        let mut current = arn;
+        for expected in expected {
+            let parent = current.parent().expect("parent should exist");
+            assert_eq!(parent.to_string(), expected);
+            assert_eq!(ArnParser::new(parent.to_string()).parse()?, parent);
+            current = parent;
+        }
+        assert_eq!(current.parent(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_ancestors() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/member1")?;
+        let ancestors: Vec<String> = arn.ancestors().map(|a| a.to_string()).collect();
+        assert_eq!(
+            ancestors,
+            vec![
+                "arn:akton:hr:acct:root/teamA/member1",
+                "arn:akton:hr:acct:root/teamA",
+                "arn:akton:hr:acct:root",
+            ]
+        );
+
+        let root_only = Arn::try_from("arn:akton:hr:acct:root")?;
+        assert_eq!(root_only.ancestors().collect::<Vec<_>>(), vec![root_only]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_for_each_ancestor() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/member1")?;
+        let mut seen: Vec<Vec<&str>> = Vec::new();
+        arn.for_each_ancestor(|parts| seen.push(parts.iter().map(Part::as_str).collect()));
+        assert_eq!(seen, vec![vec!["teamA", "member1"], vec!["teamA"], vec![]]);
+
+        // Each slice borrows from the Arn's own parts
+        let first = arn.ancestor_parts().next().unwrap_or_default();
+        assert!(core::ptr::eq(first, &arn.parts.0[..]));
+
+        let root_only = Arn::try_from("arn:akton:hr:acct:root")?;
+        assert_eq!(root_only.ancestor_parts().count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_matches_wildcards() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA")?;
+        assert!(arn.matches("arn:akton:hr:acct:root/teamA")?);
+        assert!(arn.matches("arn:*:hr:acct:root/teamA")?);
+        assert!(arn.matches("arn:akton:*:acct:root/*")?);
+        assert!(arn.matches("arn:akton:hr:*:*/team*")?);
+        assert!(arn.matches("arn:ak*:h*:*ct:r*t/*A")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_matches_non_matches() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root/teamA/member1")?;
+        assert!(!arn.matches("arn:other:hr:acct:root/teamA/member1")?);
+        assert!(!arn.matches("arn:akton:billing:acct:root/teamA/member1")?);
+        assert!(!arn.matches("arn:akton:hr:other:root/teamA/member1")?);
+        assert!(!arn.matches("arn:akton:hr:acct:other/teamA/member1")?);
+        assert!(!arn.matches("arn:akton:hr:acct:root/teamB/*")?);
+        // `*` stays within a single segment
+        assert!(!arn.matches("arn:akton:hr:acct:root/*")?);
+        assert!(arn.matches("arn:akton:hr:acct:root/*/*")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_equals_str_is_literal() -> anyhow::Result<()> {
+        let literal = Arn::try_from("arn:akton:hr:acct:root/team*")?;
+        assert!(literal.equals_str("arn:akton:hr:acct:root/team*"));
+        assert!(literal.matches("arn:akton:hr:acct:root/team*")?);
+
+        let concrete = Arn::try_from("arn:akton:hr:acct:root/teamA")?;
+        assert!(concrete.matches("arn:akton:hr:acct:root/team*")?);
+        assert!(!concrete.equals_str("arn:akton:hr:acct:root/team*"));
+        assert!(!concrete.equals_str("arn:akton:*:acct:root/teamA"));
+        assert!(concrete.equals_str("arn:akton:hr:acct:root/teamA"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_matches_invalid_pattern() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:akton:hr:acct:root")?;
+        assert_eq!(arn.matches("akton:hr:acct"), Err(ArnError::InvalidFormat));
+        assert_eq!(
+            arn.matches("*:akton:hr:acct:root"),
+            Err(ArnError::InvalidFormat)
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_arn_append_invalid_part() -> anyhow::Result<()> {
         let invalid_part = Part::new(":invalid");
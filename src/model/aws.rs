@@ -0,0 +1,182 @@
+use crate::errors::ArnError;
+use crate::model::{validate_root, Account, Arn, Category, Domain, Part, Parts, Root};
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+
+/// The root used for AWS resources that have no region, such as IAM roles.
+const GLOBAL_REGION: &str = "global";
+
+impl<'a> Arn<'a> {
+    /// Converts an AWS ARN, `arn:partition:service:region:account-id:resource`, into an owned
+    /// Arn.
+    ///
+    /// The six AWS fields map onto the five components as follows:
+    ///
+    /// | AWS          | Arn                                              |
+    /// |--------------|--------------------------------------------------|
+    /// | `partition`  | domain                                           |
+    /// | `service`    | category                                         |
+    /// | `account-id` | account                                          |
+    /// | `region`     | root, or `global` when the region is empty       |
+    /// | `resource`   | parts, one per `/`-separated segment             |
+    ///
+    /// Resource segments are stored with `Part::new_encoded`, so a `:` inside the resource, as in
+    /// `function:my-function`, is kept as `%3A` and restored by `to_aws`. For example,
+    /// `arn:aws:iam::123456789012:role/admin` becomes `arn:aws:iam:123456789012:global/role/admin`.
+    ///
+    /// AWS ARNs without an account id, such as S3 buckets, cannot be represented and fail with
+    /// the `ArnError::ParseFailure` of an empty account. An empty resource fails with
+    /// `ArnError::MissingPart`, an empty resource segment with `ArnError::InvalidPart`, and a
+    /// region outside the root charset of `Root` with `ArnError::InvalidValue`. So does a region
+    /// literally named `global`, which would convert back as an empty region.
+    pub fn from_aws(input: &str) -> Result<Arn<'static>, ArnError> {
+        let mut fields = input.splitn(6, ':');
+        if fields.next() != Some("arn") {
+            return Err(ArnError::InvalidFormat);
+        }
+        let mut next = || {
+            fields.next().ok_or(ArnError::MissingSeparator {
+                position: input.len(),
+            })
+        };
+        let (partition, service, region, account, resource) =
+            (next()?, next()?, next()?, next()?, next()?);
+
+        if resource.is_empty() {
+            return Err(ArnError::MissingPart("resource".to_string()));
+        }
+        if region == GLOBAL_REGION || (!region.is_empty() && validate_root(region).is_err()) {
+            return Err(ArnError::InvalidValue("Region", region.to_string()));
+        }
+        let parts = resource
+            .split('/')
+            .enumerate()
+            .map(|(index, segment)| {
+                Part::new_encoded(segment).map_err(|_| ArnError::InvalidPart {
+                    index,
+                    value: segment.to_string(),
+                })
+            })
+            .collect::<Result<Parts, _>>()?;
+        let root = if region.is_empty() {
+            GLOBAL_REGION
+        } else {
+            region
+        };
+        Ok(Arn::new(
+            Domain::new(partition.to_string())?,
+            Category::new(service.to_string())?,
+            Account::new(account.to_string())?,
+            Root(Cow::Owned(root.to_string())),
+            parts,
+        ))
+    }
+
+    /// Converts the Arn back into an AWS ARN, reversing the mapping of `from_aws`.
+    ///
+    /// The root becomes the region, with `global` rendered as an empty region, and the decoded
+    /// parts are joined with `/` into the resource. The output always starts with `arn:`,
+    /// whatever the Arn's scheme. An Arn without parts has no resource and fails with
+    /// `ArnError::MissingPart`.
+    pub fn to_aws(&self) -> Result<String, ArnError> {
+        if self.parts.is_empty() {
+            return Err(ArnError::MissingPart("resource".to_string()));
+        }
+        let region = match self.root() {
+            GLOBAL_REGION => "",
+            root => root,
+        };
+        let mut aws = alloc::format!(
+            "arn:{}:{}:{}:{}:",
+            self.domain(),
+            self.category(),
+            region,
+            self.account()
+        );
+        for (index, part) in self.parts.iter().enumerate() {
+            if index > 0 {
+                aws.push('/');
+            }
+            aws.push_str(&part.as_decoded());
+        }
+        Ok(aws)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aws_round_trip() -> anyhow::Result<()> {
+        for (aws, arn) in [
+            (
+                "arn:aws:ec2:us-east-1:123456789012:instance/i-0abcd1234",
+                "arn:aws:ec2:123456789012:us-east-1/instance/i-0abcd1234",
+            ),
+            (
+                "arn:aws:iam::123456789012:role/service-role/admin",
+                "arn:aws:iam:123456789012:global/role/service-role/admin",
+            ),
+            (
+                "arn:aws:lambda:eu-west-1:123456789012:function:my-function:prod",
+                "arn:aws:lambda:123456789012:eu-west-1/function%3Amy-function%3Aprod",
+            ),
+            (
+                "arn:aws-cn:sqs:cn-north-1:123456789012:my-queue",
+                "arn:aws-cn:sqs:123456789012:cn-north-1/my-queue",
+            ),
+        ] {
+            let converted = Arn::from_aws(aws)?;
+            assert_eq!(converted.to_string(), arn);
+            assert_eq!(converted, Arn::try_from(arn)?);
+            assert_eq!(converted.to_aws()?, aws);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_aws_rejects_unrepresentable_arns() {
+        // S3 bucket ARNs have no account id
+        assert_eq!(
+            Arn::from_aws("arn:aws:s3:::my-bucket/key"),
+            Err(ArnError::ParseFailure(
+                "Account",
+                "cannot be empty".to_string()
+            ))
+        );
+        assert_eq!(
+            Arn::from_aws("arn:aws:sqs:us-east-1:123456789012:"),
+            Err(ArnError::MissingPart("resource".to_string()))
+        );
+        assert_eq!(
+            Arn::from_aws("arn:aws:iam::123456789012:role//admin"),
+            Err(ArnError::InvalidPart {
+                index: 1,
+                value: String::new()
+            })
+        );
+        assert_eq!(
+            Arn::from_aws("arn:aws:svc:global:123456789012:res"),
+            Err(ArnError::InvalidValue("Region", "global".to_string()))
+        );
+        assert_eq!(
+            Arn::from_aws("arn:aws:sqs:us-east-1"),
+            Err(ArnError::MissingSeparator { position: 21 })
+        );
+        assert_eq!(
+            Arn::from_aws("qrn:aws:sqs:us-east-1:123456789012:queue"),
+            Err(ArnError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_to_aws_requires_a_resource() -> anyhow::Result<()> {
+        let arn = Arn::try_from("arn:aws:sqs:123456789012:us-east-1")?;
+        assert_eq!(
+            arn.to_aws(),
+            Err(ArnError::MissingPart("resource".to_string()))
+        );
+        Ok(())
+    }
+}
@@ -1,21 +1,83 @@
-use derive_more::{AsRef, From, Into};
-use std::borrow::Cow;
-use std::fmt;
+use crate::errors::ArnError;
+use alloc::borrow::Cow;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt;
+use derive_more::{AsRef, Deref, Into};
 /// Represents a category in the Arn system, typically indicating the service.
-
-#[derive(AsRef, From, Into, Eq, Debug, PartialEq, Clone, Hash)]
+///
+/// A category derefs to `str`, exposing its text to string methods.
+#[derive(AsRef, Deref, Into, Eq, Debug, PartialEq, Clone, Hash, PartialOrd, Ord)]
+#[deref(forward)]
 pub struct Category<'a>(pub(crate) Cow<'a, str>);
 
 impl<'a> Category<'a> {
     pub fn as_str(&self) -> &str {
         &self.0
     }
-    pub fn new(value: impl Into<Cow<'a, str>>) -> Self {
-        Category(value.into())
+
+    /// Creates a category, rejecting values containing the `:` separator that would not
+    /// survive a round trip through an Arn string.
+    pub fn new(value: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
+        let value = value.into();
+        if value.contains(':') {
+            return Err(ArnError::InvalidValue("Category", value.into_owned()));
+        }
+        Ok(Category(value))
     }
     pub fn into_owned(self) -> Category<'static> {
         Category(Cow::Owned(self.0.into_owned()))
     }
+
+    /// Returns the well-known category this is, or `None` for any other value.
+    ///
+    /// Matching is exact, so `HR` is not recognized as `KnownCategory::Hr`.
+    pub fn as_known(&self) -> Option<KnownCategory> {
+        KnownCategory::ALL
+            .into_iter()
+            .find(|known| known.as_str() == self.as_str())
+    }
+}
+
+/// The categories used across the platform, for matching on a category without comparing
+/// strings. Any other string is still a valid `Category`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum KnownCategory {
+    /// `system`, the default category.
+    System,
+    /// `hr`.
+    Hr,
+    /// `service`.
+    Service,
+}
+
+impl KnownCategory {
+    const ALL: [KnownCategory; 3] = [
+        KnownCategory::System,
+        KnownCategory::Hr,
+        KnownCategory::Service,
+    ];
+
+    /// Returns the category text, e.g. `hr`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            KnownCategory::System => "system",
+            KnownCategory::Hr => "hr",
+            KnownCategory::Service => "service",
+        }
+    }
+}
+
+impl From<KnownCategory> for Category<'static> {
+    fn from(known: KnownCategory) -> Self {
+        Category(Cow::Borrowed(known.as_str()))
+    }
+}
+
+impl fmt::Display for KnownCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl<'a> Default for Category<'a> {
@@ -30,11 +92,11 @@ impl<'a> fmt::Display for Category<'a> {
     }
 }
 
-impl<'a> std::str::FromStr for Category<'a> {
-    type Err = std::convert::Infallible;
+impl<'a> core::str::FromStr for Category<'a> {
+    type Err = ArnError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Category(Cow::Owned(s.to_owned())))
+        Category::new(s.to_owned())
     }
 }
 impl<'a> From<Category<'a>> for String {
@@ -45,13 +107,46 @@ impl<'a> From<Category<'a>> for String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_category_as_known() {
+        for (value, known) in [
+            ("system", KnownCategory::System),
+            ("hr", KnownCategory::Hr),
+            ("service", KnownCategory::Service),
+        ] {
+            assert_eq!(Category::new(value).unwrap().as_known(), Some(known));
+            assert_eq!(Category::from(known).as_str(), value);
+        }
+        assert_eq!(Category::default().as_known(), Some(KnownCategory::System));
+        assert_eq!(Category::new("billing").unwrap().as_known(), None);
+        assert_eq!(Category::new("HR").unwrap().as_known(), None);
+    }
+
+    #[test]
+    fn test_category_derefs_to_str() {
+        let category = Category::new("service").unwrap();
+        assert!(category.ends_with("ice"));
+        assert_eq!(category.to_uppercase(), "SERVICE");
+    }
 
     #[test]
     fn test_category_creation() {
-        let category = Category::new("test");
+        let category = Category::new("test").unwrap();
         assert_eq!(category.as_str(), "test");
     }
 
+    #[test]
+    fn test_category_rejects_separator() {
+        assert_eq!(
+            Category::new("hr:payroll"),
+            Err(ArnError::InvalidValue("Category", "hr:payroll".to_string()))
+        );
+        assert!("hr:payroll".parse::<Category>().is_err());
+    }
+
     #[test]
     fn test_category_default() {
         let category = Category::default();
@@ -60,7 +155,7 @@ mod tests {
 
     #[test]
     fn test_category_display() {
-        let category = Category::new("example");
+        let category = Category::new("example").unwrap();
         assert_eq!(format!("{}", category), "example");
     }
 
@@ -72,16 +167,16 @@ mod tests {
 
     #[test]
     fn test_category_equality() {
-        let category1 = Category::new("test");
-        let category2 = Category::new("test");
-        let category3 = Category::new("other");
+        let category1 = Category::new("test").unwrap();
+        let category2 = Category::new("test").unwrap();
+        let category3 = Category::new("other").unwrap();
         assert_eq!(category1, category2);
         assert_ne!(category1, category3);
     }
 
     #[test]
     fn test_category_into_string() {
-        let category = Category::new("test");
+        let category = Category::new("test").unwrap();
         let string: String = category.into();
         assert_eq!(string, "test");
     }
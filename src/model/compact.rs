@@ -0,0 +1,268 @@
+use crate::errors::ArnError;
+use crate::model::{
+    Account, Arn, Category, Domain, Part, Parts, Root, Scheme, DEFAULT_PART_SEPARATOR,
+};
+use crate::parser::ArnParser;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// An Arn stored as its canonical string in a single allocation, with the byte offsets of each
+/// component, for keeping many parsed Arns in memory.
+///
+/// Every accessor is a cheap slice into the stored string, and the value is much smaller to
+/// move than an `Arn`, which holds each component in its own `Cow`. `Arn` itself keeps that
+/// layout, so its size is unchanged; this is an opt-in storage type beside it. Convert with
+/// `to_arn` to use the rest of the `Arn` API without copying.
+///
+/// Equality, ordering, and hashing use the canonical string alone, and `CompactArn` implements
+/// `Borrow<str>`, so a `HashSet<CompactArn>` can be queried with a `&str` such as
+/// `set.contains("arn:akton:hr:acct:root")` without parsing it. Two compact Arns with the same
+/// text but different part separators therefore compare equal.
+#[derive(Debug, Clone)]
+pub struct CompactArn {
+    /// The canonical form, e.g. `arn:akton:hr:acct:root/teamA`.
+    text: Box<str>,
+    /// The end offsets of the scheme, domain, category, account, and root within `text`.
+    ends: [usize; 5],
+    /// The separator between the root and each part.
+    part_separator: char,
+}
+
+impl CompactArn {
+    /// Validates `input` and stores it as a compact Arn, without building an `Arn` first.
+    ///
+    /// Accepts exactly what `ArnParser::parse` accepts, with the default scheme and separator.
+    /// An uppercase scheme is stored lowercase, as `parse` does.
+    pub fn parse(input: &str) -> Result<Self, ArnError> {
+        ArnParser::new(input).validate()?;
+        let mut text = input.to_string();
+        let scheme_end = text.find(':').unwrap_or_default();
+        text[..scheme_end].make_ascii_lowercase();
+        Ok(Self::from_canonical(
+            text.into_boxed_str(),
+            DEFAULT_PART_SEPARATOR,
+        ))
+    }
+
+    /// Computes the component offsets of a valid canonical string.
+    fn from_canonical(text: Box<str>, part_separator: char) -> Self {
+        let mut ends = [text.len(); 5];
+        let mut colons = text.match_indices(':').map(|(index, _)| index);
+        for end in ends.iter_mut().take(4) {
+            *end = colons.next().unwrap_or(text.len());
+        }
+        let after_account = ends[3] + 1;
+        if let Some(index) = text[after_account..].find(part_separator) {
+            ends[4] = after_account + index;
+        }
+        CompactArn {
+            text,
+            ends,
+            part_separator,
+        }
+    }
+
+    /// Returns the byte range of component `index`, between the previous separator and its end.
+    fn component(&self, index: usize) -> &str {
+        let start = if index == 0 {
+            0
+        } else {
+            self.ends[index - 1] + 1
+        };
+        &self.text[start..self.ends[index]]
+    }
+
+    /// Returns the whole canonical string.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the scheme, e.g. `arn`.
+    pub fn scheme(&self) -> &str {
+        self.component(0)
+    }
+
+    /// Returns the domain component.
+    pub fn domain(&self) -> &str {
+        self.component(1)
+    }
+
+    /// Returns the category component.
+    pub fn category(&self) -> &str {
+        self.component(2)
+    }
+
+    /// Returns the account component.
+    pub fn account(&self) -> &str {
+        self.component(3)
+    }
+
+    /// Returns the root component.
+    pub fn root(&self) -> &str {
+        self.component(4)
+    }
+
+    /// Returns the separator between the root and each part.
+    pub fn part_separator(&self) -> char {
+        self.part_separator
+    }
+
+    /// Iterates over the parts that follow the root, in order.
+    pub fn parts(&self) -> impl Iterator<Item = &str> {
+        let rest = self.text.get(self.ends[4] + 1..).unwrap_or_default();
+        rest.split(self.part_separator)
+            .filter(|part| !part.is_empty())
+    }
+
+    /// Returns an `Arn` whose components borrow from this compact Arn.
+    pub fn to_arn(&self) -> Arn<'_> {
+        let parts: Parts = self.parts().map(|part| Part(Cow::Borrowed(part))).collect();
+        let mut arn = Arn::new(
+            Domain(Cow::Borrowed(self.domain())),
+            Category(Cow::Borrowed(self.category())),
+            Account(Cow::Borrowed(self.account())),
+            Root(Cow::Borrowed(self.root())),
+            parts,
+        );
+        arn.scheme = Scheme(Cow::Borrowed(self.scheme())).into_static();
+        arn.part_separator = self.part_separator;
+        arn
+    }
+}
+
+/// Stores the canonical string of `arn`, keeping its part separator.
+impl From<&Arn<'_>> for CompactArn {
+    fn from(arn: &Arn<'_>) -> Self {
+        let mut text = String::new();
+        // Writing to a String cannot fail.
+        let _ = arn.write_canonical(&mut text);
+        Self::from_canonical(text.into_boxed_str(), arn.part_separator)
+    }
+}
+
+impl PartialEq for CompactArn {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for CompactArn {}
+
+/// Hashes the canonical string exactly as `str` does, as `Borrow<str>` requires.
+impl Hash for CompactArn {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.hash(state);
+    }
+}
+
+impl PartialOrd for CompactArn {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CompactArn {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.text.cmp(&other.text)
+    }
+}
+
+impl Borrow<str> for CompactArn {
+    fn borrow(&self) -> &str {
+        &self.text
+    }
+}
+
+impl core::str::FromStr for CompactArn {
+    type Err = ArnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CompactArn::parse(s)
+    }
+}
+
+impl fmt::Display for CompactArn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_compact_arn_matches_arn() -> anyhow::Result<()> {
+        for input in [
+            "arn:akton:hr:acct:root",
+            "arn:akton:hr:acct:root/teamA",
+            "arn:akton::acct:root/teamA/team1/member",
+            "ARN:akton:HR:acct:root/TeamA",
+        ] {
+            let arn = ArnParser::new(input).parse()?;
+            let compact = CompactArn::parse(input)?;
+            assert_eq!(compact.scheme(), arn.scheme());
+            assert_eq!(compact.domain(), arn.domain());
+            assert_eq!(compact.category(), arn.category());
+            assert_eq!(compact.account(), arn.account());
+            assert_eq!(compact.root(), arn.root());
+            let parts: Vec<&str> = arn.parts().iter().map(Part::as_str).collect();
+            assert_eq!(compact.parts().collect::<Vec<_>>(), parts);
+            assert_eq!(compact.to_string(), arn.to_string());
+            assert_eq!(compact.to_arn(), arn);
+            assert_eq!(CompactArn::from(&arn), compact);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_arn_keeps_part_separator() -> anyhow::Result<()> {
+        let arn = ArnParser::new("arn:akton:hr:acct:root.teamA.team1")
+            .with_part_separator('.')?
+            .parse()?;
+        let compact = CompactArn::from(&arn);
+        assert_eq!(compact.root(), "root");
+        assert_eq!(compact.parts().collect::<Vec<_>>(), ["teamA", "team1"]);
+        assert_eq!(compact.to_arn(), arn);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_arn_rejects_invalid_input() {
+        assert_eq!(
+            CompactArn::parse("arn:akton:hr:acct:root//teamA"),
+            Err(ArnError::EmptyPart { position: 23 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_compact_arn_string_lookup() -> anyhow::Result<()> {
+        let arns = [
+            Arn::try_from("arn:akton:hr:acct:root")?.into_owned(),
+            Arn::try_from("arn:akton:hr:acct:root/teamA")?.into_owned(),
+        ];
+        let set: std::collections::HashSet<CompactArn> =
+            arns.iter().map(CompactArn::from).collect();
+        assert!(set.contains("arn:akton:hr:acct:root"));
+        assert!(set.contains("arn:akton:hr:acct:root/teamA"));
+        assert!(!set.contains("arn:akton:hr:acct:root/teamB"));
+
+        let tree: alloc::collections::BTreeSet<CompactArn> =
+            arns.iter().map(CompactArn::from).collect();
+        assert!(tree.contains("arn:akton:hr:acct:root/teamA"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_arn_is_smaller_than_arn() {
+        // Only the compact form is measured; `Arn` stores its components separately
+        assert!(core::mem::size_of::<CompactArn>() < core::mem::size_of::<Arn>() / 2);
+    }
+}
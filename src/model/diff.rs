@@ -0,0 +1,126 @@
+use crate::model::Arn;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// How a single component compares between two Arns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentChange<'s> {
+    /// The component is identical in both Arns.
+    Same,
+    /// The component differs, holding the value in each Arn.
+    Changed { from: &'s str, to: &'s str },
+}
+
+/// How the part at one position compares between two Arns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartChange<'s> {
+    /// Both Arns have this part at this position.
+    Same(&'s str),
+    /// Both Arns have a part at this position, but with different values.
+    Changed { from: &'s str, to: &'s str },
+    /// Only the other Arn has a part at this position.
+    Added(&'s str),
+    /// Only this Arn has a part at this position.
+    Removed(&'s str),
+}
+
+/// A component-by-component comparison of two Arns, produced by `Arn::diff`.
+///
+/// Parts are compared by position, so inserting a part in the middle reports every later part
+/// as changed, followed by one added part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArnDiff<'s> {
+    pub scheme: ComponentChange<'s>,
+    pub domain: ComponentChange<'s>,
+    pub category: ComponentChange<'s>,
+    pub account: ComponentChange<'s>,
+    pub root: ComponentChange<'s>,
+    pub parts: Vec<PartChange<'s>>,
+}
+
+impl<'s> ArnDiff<'s> {
+    pub(crate) fn new(from: &'s Arn, to: &'s Arn) -> Self {
+        let count = from.parts().len().max(to.parts().len());
+        let parts = (0..count)
+            .map(
+                |index| match (from.parts().get(index), to.parts().get(index)) {
+                    (Some(a), Some(b)) if a == b => PartChange::Same(a.as_str()),
+                    (Some(a), Some(b)) => PartChange::Changed {
+                        from: a.as_str(),
+                        to: b.as_str(),
+                    },
+                    (None, Some(b)) => PartChange::Added(b.as_str()),
+                    (Some(a), None) => PartChange::Removed(a.as_str()),
+                    (None, None) => unreachable!("index is below the longer parts length"),
+                },
+            )
+            .collect();
+
+        ArnDiff {
+            scheme: compare(from.scheme(), to.scheme()),
+            domain: compare(from.domain(), to.domain()),
+            category: compare(from.category(), to.category()),
+            account: compare(from.account(), to.account()),
+            root: compare(from.root(), to.root()),
+            parts,
+        }
+    }
+
+    /// Returns true when the two Arns are identical.
+    pub fn is_same(&self) -> bool {
+        self.components()
+            .all(|(_, change)| change == ComponentChange::Same)
+            && self
+                .parts
+                .iter()
+                .all(|part| matches!(part, PartChange::Same(_)))
+    }
+
+    fn components(&self) -> impl Iterator<Item = (&'static str, ComponentChange<'s>)> {
+        [
+            ("scheme", self.scheme),
+            ("domain", self.domain),
+            ("category", self.category),
+            ("account", self.account),
+            ("root", self.root),
+        ]
+        .into_iter()
+    }
+}
+
+fn compare<'s>(from: &'s str, to: &'s str) -> ComponentChange<'s> {
+    if from == to {
+        ComponentChange::Same
+    } else {
+        ComponentChange::Changed { from, to }
+    }
+}
+
+impl fmt::Display for ArnDiff<'_> {
+    /// Lists each difference on its own line, e.g. `account: acct -> other`, or writes
+    /// `no differences` when the Arns are identical.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_same() {
+            return write!(f, "no differences");
+        }
+        let mut lines = Vec::new();
+        for (name, change) in self.components() {
+            if let ComponentChange::Changed { from, to } = change {
+                lines.push(alloc::format!("{name}: {from} -> {to}"));
+            }
+        }
+        for (index, change) in self.parts.iter().enumerate() {
+            match change {
+                PartChange::Same(_) => {}
+                PartChange::Changed { from, to } => {
+                    lines.push(alloc::format!("part {index}: {from} -> {to}"))
+                }
+                PartChange::Added(part) => lines.push(alloc::format!("part {index} added: {part}")),
+                PartChange::Removed(part) => {
+                    lines.push(alloc::format!("part {index} removed: {part}"))
+                }
+            }
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
@@ -1,9 +1,15 @@
 use crate::errors::ArnError;
-use derive_more::{AsRef, From, Into};
-use std::borrow::Cow;
-use std::fmt;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::fmt;
+use derive_more::{AsRef, Deref, Into};
 
-#[derive(AsRef, From, Into, Eq, Debug, PartialEq, Clone)]
+/// Represents the domain of an Arn, such as `akton-internal`.
+///
+/// Domains must start with a letter and may only contain lowercase ASCII letters, digits,
+/// and `-`. A domain derefs to `str`, exposing its text to string methods like `starts_with`.
+#[derive(AsRef, Deref, Into, Eq, Debug, PartialEq, Clone, Hash, PartialOrd, Ord)]
+#[deref(forward)]
 pub struct Domain<'a>(pub(crate) Cow<'a, str>);
 
 impl<'a> Domain<'a> {
@@ -17,7 +23,20 @@ impl<'a> Domain<'a> {
     pub fn new(value: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
         let val = value.into();
         if val.is_empty() {
-            Err(ArnError::ParseFailure("Domain", "cannot be empty".to_string()))
+            Err(ArnError::ParseFailure(
+                "Domain",
+                "cannot be empty".to_string(),
+            ))
+        } else if !val.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            Err(ArnError::ParseFailure(
+                "Domain",
+                alloc::format!("must start with a letter, found `{val}`"),
+            ))
+        } else if !val
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        {
+            Err(ArnError::InvalidValue("Domain", val.into_owned()))
         } else {
             Ok(Domain(val))
         }
@@ -36,7 +55,7 @@ impl<'a> fmt::Display for Domain<'a> {
     }
 }
 
-impl<'a> std::str::FromStr for Domain<'a> {
+impl<'a> core::str::FromStr for Domain<'a> {
     type Err = ArnError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -51,6 +70,16 @@ impl<'a> From<Domain<'a>> for String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_domain_derefs_to_str() -> anyhow::Result<()> {
+        let domain = Domain::new("akton-internal")?;
+        assert!(domain.starts_with("ak"));
+        assert_eq!(domain.len(), 14);
+        assert_eq!(domain.split('-').count(), 2);
+        Ok(())
+    }
 
     #[test]
     fn test_domain_creation() {
@@ -86,6 +115,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_domain_valid_charset() {
+        for value in ["akton", "akton-internal", "region1", "a-1-b"] {
+            assert!(Domain::new(value).is_ok(), "{value} should be valid");
+        }
+    }
+
+    #[test]
+    fn test_domain_rejects_invalid_characters() {
+        for value in ["has space", "Akton", "AKTON", "ak:ton", "ak/ton", "ak_ton"] {
+            assert_eq!(
+                Domain::new(value),
+                Err(ArnError::InvalidValue("Domain", value.to_string())),
+                "{value} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_domain_must_start_with_letter() {
+        assert_eq!(
+            Domain::new(""),
+            Err(ArnError::ParseFailure(
+                "Domain",
+                "cannot be empty".to_string()
+            ))
+        );
+        for value in ["1akton", "-akton"] {
+            assert_eq!(
+                Domain::new(value),
+                Err(ArnError::ParseFailure(
+                    "Domain",
+                    format!("must start with a letter, found `{value}`")
+                )),
+                "{value} should be rejected"
+            );
+        }
+        assert!(Domain::new("a1-b").is_ok());
+    }
+
     #[test]
     fn test_domain_into_string() {
         let domain = Domain::new("test").unwrap();
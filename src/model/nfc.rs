@@ -0,0 +1,70 @@
+use crate::model::{Account, Arn, Category, Domain, Part, Root, Scheme};
+use alloc::borrow::Cow;
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+/// Returns `value` in Unicode Normalization Form C, borrowing it when it already is.
+pub(crate) fn nfc(value: Cow<'_, str>) -> Cow<'_, str> {
+    if is_nfc(&value) {
+        value
+    } else {
+        Cow::Owned(value.nfc().collect())
+    }
+}
+
+impl<'a> Arn<'a> {
+    /// Normalizes every component to Unicode Normalization Form C, so that Arns which look
+    /// identical, such as one with a precomposed `é` and one with `e` and a combining accent,
+    /// also compare equal.
+    ///
+    /// Components already in NFC, including every ASCII component, are kept as they are.
+    pub fn into_nfc(self) -> Arn<'a> {
+        Arn {
+            domain: Domain(nfc(self.domain.0)),
+            category: Category(nfc(self.category.0)),
+            account: Account(nfc(self.account.0)),
+            root: Root(nfc(self.root.0)),
+            parts: self
+                .parts
+                .0
+                .into_iter()
+                .map(|part| Part(nfc(part.0)))
+                .collect(),
+            scheme: Scheme(nfc(self.scheme.0)),
+            part_separator: self.part_separator,
+            original: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArnParser;
+
+    #[test]
+    fn test_into_nfc_equates_precomposed_and_decomposed() -> anyhow::Result<()> {
+        let precomposed =
+            ArnParser::new("arn:akton:caf\u{e9}:acct:root/r\u{e9}sum\u{e9}").parse()?;
+        let decomposed =
+            ArnParser::new("arn:akton:cafe\u{301}:acct:root/re\u{301}sume\u{301}").parse()?;
+        assert_ne!(precomposed, decomposed);
+
+        let normalized = decomposed.into_nfc();
+        assert_eq!(normalized, precomposed);
+        assert_eq!(normalized.to_string(), precomposed.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_nfc_borrows_normalized_components() -> anyhow::Result<()> {
+        let arn = ArnParser::new("arn:akton:hr:acct:root/teamA")
+            .parse()?
+            .into_nfc();
+        assert!(matches!(arn.category.0, Cow::Borrowed(_)));
+        assert!(arn
+            .parts
+            .iter()
+            .all(|part| matches!(part.0, Cow::Borrowed(_))));
+        Ok(())
+    }
+}
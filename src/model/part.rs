@@ -1,9 +1,20 @@
 use crate::errors::ArnError;
-use derive_more::{AsRef, From, Into};
-use std::borrow::Cow;
-use std::fmt;
+use alloc::borrow::Cow;
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use core::fmt;
+use derive_more::{AsRef, Deref, Into};
 
-#[derive(AsRef, From, Into, Eq, Debug, PartialEq, Clone)]
+/// Represents a single segment of the resource path in an Arn.
+///
+/// Parts cannot be empty or contain the `/` and `:` separators, so serialized parts always
+/// parse back into the same segments. Parts also cannot start or end with whitespace, which a
+/// strict parser would reject at the end of an Arn. Values that need the separators can be
+/// stored percent-encoded with `Part::new_encoded` and recovered with `Part::as_decoded`.
+///
+/// A part derefs to `str`, exposing its raw (still encoded) text to string methods.
+#[derive(AsRef, Deref, Into, Eq, Debug, PartialEq, Clone, Hash, PartialOrd, Ord)]
+#[deref(forward)]
 pub struct Part<'a>(pub(crate) Cow<'a, str>);
 impl<'a> Part<'a> {
     pub fn as_str(&self) -> &str {
@@ -25,8 +36,71 @@ impl<'a> Part<'a> {
                 "cannot be empty".to_string(),
             ));
         }
+        if value.starts_with(char::is_whitespace) {
+            return Err(ArnError::UnexpectedWhitespace { position: 0 });
+        }
+        let trimmed = value.trim_end();
+        if trimmed.len() != value.len() {
+            return Err(ArnError::UnexpectedWhitespace {
+                position: trimmed.len(),
+            });
+        }
         Ok(Part(value))
     }
+
+    /// Creates a part from an arbitrary value, percent-encoding `%` as `%25`, `/` as `%2F`,
+    /// and `:` as `%3A` so the value survives being serialized in an Arn.
+    ///
+    /// The part stores and displays the encoded form; use `Part::as_decoded` to get the
+    /// original value back. Whitespace is not encoded, so like `Part::new` this fails with
+    /// `ArnError::ParseFailure` when `value` is empty and `ArnError::UnexpectedWhitespace` when it
+    /// starts or ends with whitespace.
+    pub fn new_encoded(value: &str) -> Result<Part<'static>, ArnError> {
+        let mut encoded = String::with_capacity(value.len());
+        for c in value.chars() {
+            match c {
+                '%' => encoded.push_str("%25"),
+                '/' => encoded.push_str("%2F"),
+                ':' => encoded.push_str("%3A"),
+                c => encoded.push(c),
+            }
+        }
+        Part::new(encoded)
+    }
+
+    /// Returns the value with the `%25`, `%2F`, and `%3A` escapes decoded.
+    ///
+    /// Escapes are matched case-insensitively. Any other `%` sequence is left untouched, and the
+    /// value is borrowed when it contains no `%` at all.
+    pub fn as_decoded(&self) -> Cow<'_, str> {
+        if !self.0.contains('%') {
+            return Cow::Borrowed(&self.0);
+        }
+        let mut decoded = String::with_capacity(self.0.len());
+        let mut rest: &str = &self.0;
+        while let Some(index) = rest.find('%') {
+            decoded.push_str(&rest[..index]);
+            let escape = rest.get(index..index + 3).unwrap_or_default();
+            let c = match escape.to_ascii_uppercase().as_str() {
+                "%25" => Some('%'),
+                "%2F" => Some('/'),
+                "%3A" => Some(':'),
+                _ => None,
+            };
+            match c {
+                Some(c) => {
+                    decoded.push(c);
+                    rest = &rest[index + 3..];
+                }
+                None => {
+                    decoded.push('%');
+                    rest = &rest[index + 1..];
+                }
+            }
+        }
+        decoded.push_str(rest);
+        Cow::Owned(decoded)
+    }
 }
 
 impl<'a> fmt::Display for Part<'a> {
@@ -34,7 +108,7 @@ impl<'a> fmt::Display for Part<'a> {
         write!(f, "{}", self.0)
     }
 }
-impl<'a> std::str::FromStr for Part<'a> {
+impl<'a> core::str::FromStr for Part<'a> {
     type Err = ArnError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Part::new(Cow::Owned(s.to_owned()))
@@ -49,6 +123,16 @@ impl<'a> From<Part<'a>> for String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_part_derefs_to_str() -> anyhow::Result<()> {
+        let part = Part::new("teamA")?;
+        assert!(part.starts_with("team"));
+        assert_eq!(part.to_lowercase(), "teama");
+        assert_eq!(&*Part::new_encoded("a/b")?, "a%2Fb");
+        Ok(())
+    }
 
     #[test]
     fn test_part_creation() -> anyhow::Result<()> {
@@ -80,6 +164,68 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_part_equality_borrowed_and_owned() -> anyhow::Result<()> {
+        let borrowed = Part::new("segment1")?;
+        let owned = Part::new(String::from("segment1"))?;
+        assert_eq!(borrowed, owned);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_rejects_separators_and_empty() {
+        assert_eq!(Part::new("team/extra"), Err(ArnError::InvalidPartFormat));
+        assert_eq!(Part::new("team:extra"), Err(ArnError::InvalidPartFormat));
+        assert!(matches!(
+            Part::new(""),
+            Err(ArnError::ParseFailure("Part", _))
+        ));
+    }
+
+    #[test]
+    fn test_part_encoded_round_trip() -> anyhow::Result<()> {
+        for (value, encoded) in [
+            ("a/b", "a%2Fb"),
+            ("a:b", "a%3Ab"),
+            ("100%", "100%25"),
+            ("%2F/:", "%252F%2F%3A"),
+        ] {
+            let part = Part::new_encoded(value)?;
+            assert_eq!(part.as_str(), encoded);
+            assert_eq!(part.as_decoded(), value);
+        }
+        assert!(Part::new_encoded("").is_err());
+        assert_eq!(
+            Part::new_encoded(" a/b"),
+            Err(ArnError::UnexpectedWhitespace { position: 0 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_as_decoded() -> anyhow::Result<()> {
+        assert!(matches!(
+            Part::new("plain")?.as_decoded(),
+            Cow::Borrowed("plain")
+        ));
+        assert_eq!(Part::new("a%2fb")?.as_decoded(), "a/b");
+        assert_eq!(Part::new("50%off%")?.as_decoded(), "50%off%");
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_rejects_surrounding_whitespace() {
+        assert_eq!(
+            Part::new(" team"),
+            Err(ArnError::UnexpectedWhitespace { position: 0 })
+        );
+        assert_eq!(
+            Part::new("team\t"),
+            Err(ArnError::UnexpectedWhitespace { position: 4 })
+        );
+        assert!(Part::new("team one").is_ok());
+    }
+
     #[test]
     fn test_part_into_string() -> anyhow::Result<()> {
         let part = Part::new("segment")?;
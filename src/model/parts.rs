@@ -1,9 +1,41 @@
+use crate::errors::ArnError;
 use crate::Part;
-use std::fmt;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Index;
+
+/// Storage for the parts of an Arn. With the `smallvec` feature, up to four parts are kept inline
+/// without a heap allocation.
+#[cfg(feature = "smallvec")]
+pub(crate) type PartsVec<'a> = smallvec::SmallVec<Part<'a>, 4>;
+#[cfg(not(feature = "smallvec"))]
+pub(crate) type PartsVec<'a> = Vec<Part<'a>>;
+
+/// The separator placed between the root and each part unless another is configured.
+pub(crate) const DEFAULT_PART_SEPARATOR: char = '/';
+
+/// Validates a configured part separator.
+///
+/// Rejects `:`, which already separates components, any character that may appear in a root
+/// or part, such as alphanumerics, `_`, and `-`, which would re-parse as a different split, and
+/// whitespace and `%`, which parts and their percent-encoding reserve.
+pub(crate) fn check_part_separator(separator: char) -> Result<char, ArnError> {
+    if separator.is_alphanumeric()
+        || separator.is_whitespace()
+        || matches!(separator, ':' | '_' | '-' | '%')
+    {
+        return Err(ArnError::InvalidValue(
+            "Part separator",
+            separator.to_string(),
+        ));
+    }
+    Ok(separator)
+}
 
 /// Represents a collection of parts in the Arn, handling multiple segments.
-#[derive(Debug, PartialEq, Clone, Eq, Default)]
-pub struct Parts<'a>(pub(crate) Vec<Part<'a>>);
+#[derive(Debug, PartialEq, Clone, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Parts<'a>(pub(crate) PartsVec<'a>);
 
 impl<'a> Parts<'a> {
     /// Constructs a new collection of `Parts`.
@@ -12,7 +44,30 @@ impl<'a> Parts<'a> {
     ///
     /// * `parts` - A vector of `Part` representing the parts of the Arn.
     pub fn new(parts: Vec<Part<'a>>) -> Self {
-        Parts(parts)
+        Parts(parts.into_iter().collect())
+    }
+
+    /// Constructs an empty collection with room for at least `capacity` parts, so adding that
+    /// many does not reallocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Parts(PartsVec::with_capacity(capacity))
+    }
+
+    /// Parses a `/`-joined resource path such as `teamA/member1/widget` into parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The path to split on `/`. An empty string yields no parts.
+    ///
+    /// # Returns
+    ///
+    /// Returns an error if any segment fails `Part::new`, including the empty segments produced
+    /// by leading, trailing, or doubled slashes.
+    pub fn parse(input: &'a str) -> Result<Self, ArnError> {
+        if input.is_empty() {
+            return Ok(Parts::default());
+        }
+        input.split('/').map(Part::new).collect()
     }
 
     /// Adds a part to the collection.
@@ -28,12 +83,73 @@ impl<'a> Parts<'a> {
         self
     }
 
+    /// Appends a part in place, for callers holding a `&mut Parts`.
+    ///
+    /// Use `add_part` for the consuming, chaining style.
+    pub fn push(&mut self, part: Part<'a>) {
+        self.0.push(part);
+    }
+
+    /// Removes and returns the last part, or `None` if there are no parts.
+    pub fn pop(&mut self) -> Option<Part<'a>> {
+        self.0.pop()
+    }
+
     /// Converts the Parts into an owned version with 'static lifetime
     pub fn into_owned(self) -> Parts<'static> {
         Parts(self.0.into_iter().map(|part| part.into_owned()).collect())
     }
+
+    /// Returns an iterator over the parts in order. Yields nothing when there are no parts.
+    pub fn iter(&self) -> core::slice::Iter<'_, Part<'a>> {
+        self.0.iter()
+    }
+
+    /// Returns the number of parts.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true when there are no parts, i.e. the Arn ends at its root.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns true if any part's decoded value equals `value`.
+    ///
+    /// Parts are compared via `Part::as_decoded`, so `a/b` finds a part created with
+    /// `Part::new_encoded("a/b")`.
+    pub fn contains(&self, value: &str) -> bool {
+        self.0.iter().any(|part| part.as_decoded() == value)
+    }
+
+    /// Returns the part at `index`, or `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&Part<'a>> {
+        self.0.get(index)
+    }
+}
+
+impl<'a> Index<usize> for Parts<'a> {
+    type Output = Part<'a>;
+
+    /// Returns the part at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, like indexing a `Vec`. Use `Parts::get` to avoid this.
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
 }
 
+impl<'p, 'a> IntoIterator for &'p Parts<'a> {
+    type Item = &'p Part<'a>;
+    type IntoIter = core::slice::Iter<'p, Part<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 
 impl<'a> FromIterator<Part<'a>> for Parts<'a> {
     fn from_iter<T: IntoIterator<Item = Part<'a>>>(iter: T) -> Self {
@@ -41,6 +157,20 @@ impl<'a> FromIterator<Part<'a>> for Parts<'a> {
     }
 }
 
+impl<'a> Extend<Part<'a>> for Parts<'a> {
+    fn extend<T: IntoIterator<Item = Part<'a>>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl core::str::FromStr for Parts<'static> {
+    type Err = ArnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parts::parse(s).map(Parts::into_owned)
+    }
+}
+
 impl<'a> fmt::Display for Parts<'a> {
     /// Formats the collection of parts as a string, joining them with '/'.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -57,6 +187,25 @@ impl<'a> fmt::Display for Parts<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::String;
+    use alloc::{format, vec};
+
+    #[test]
+    fn test_check_part_separator() {
+        for separator in ['/', '.', '|', '#'] {
+            assert_eq!(check_part_separator(separator), Ok(separator));
+        }
+        for separator in [':', '_', '-', '%', ' ', '\t', 'a', 'Z', '7', '\u{e9}'] {
+            assert_eq!(
+                check_part_separator(separator),
+                Err(ArnError::InvalidValue(
+                    "Part separator",
+                    separator.to_string()
+                )),
+                "{separator:?}"
+            );
+        }
+    }
 
     #[test]
     fn test_parts_creation() -> anyhow::Result<()> {
@@ -74,6 +223,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parts_push_and_pop() -> anyhow::Result<()> {
+        let mut parts = Parts::new(vec![Part::new("segment1")?]);
+        parts.push(Part::new("segment2")?);
+        parts.push(Part::new("segment3")?);
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts.to_string(), "segment1/segment2/segment3");
+
+        assert_eq!(parts.pop(), Some(Part::new("segment3")?));
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts.pop(), Some(Part::new("segment2")?));
+        assert_eq!(parts.pop(), Some(Part::new("segment1")?));
+        assert!(parts.is_empty());
+        assert_eq!(parts.pop(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parts_display_round_trip() -> anyhow::Result<()> {
+        for text in ["teamA", "teamA/team1", "teamA/team1/member"] {
+            let parts = Parts::parse(text)?;
+            assert_eq!(parts.to_string(), text);
+            assert_eq!(Parts::parse(&parts.to_string())?, parts);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parts_with_capacity() -> anyhow::Result<()> {
+        let names: Vec<String> = (0..100).map(|i| format!("segment{i}")).collect();
+        let mut parts = Parts::with_capacity(100);
+        let capacity = parts.0.capacity();
+        assert!(capacity >= 100);
+        for name in &names {
+            parts.push(Part::new(name.as_str())?);
+        }
+        // All 100 parts fit in the initial allocation
+        assert_eq!(parts.0.capacity(), capacity);
+        assert_eq!(parts.len(), 100);
+        assert_eq!(parts[99].as_str(), "segment99");
+        assert_eq!(parts.to_string(), names.join("/"));
+        Ok(())
+    }
+
     #[test]
     fn test_parts_from_iterator() -> anyhow::Result<()> {
         let parts: Result<Parts, _> = vec!["segment1", "segment2", "segment3"]
@@ -89,6 +282,106 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parts_collect_and_extend() -> anyhow::Result<()> {
+        let mut parts: Parts = [Part::new("teamA")?, Part::new("member1")?]
+            .into_iter()
+            .collect();
+        assert_eq!(parts.len(), 2);
+
+        parts.extend([Part::new("widget")?, Part::new("gear")?]);
+        assert_eq!(parts.to_string(), "teamA/member1/widget/gear");
+        parts.extend(core::iter::empty());
+        assert_eq!(parts.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parts_iter() -> anyhow::Result<()> {
+        let parts = Parts::new(vec![Part::new("segment1")?, Part::new("segment2")?]);
+        let collected: Vec<&str> = parts.iter().map(Part::as_str).collect();
+        assert_eq!(collected, vec!["segment1", "segment2"]);
+
+        let mut count = 0;
+        for part in &parts {
+            assert!(part.as_str().starts_with("segment"));
+            count += 1;
+        }
+        assert_eq!(count, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parts_iter_empty() {
+        let parts = Parts::default();
+        assert_eq!(parts.iter().next(), None);
+        assert_eq!((&parts).into_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_parts_len_and_indexing() -> anyhow::Result<()> {
+        let parts = Parts::new(vec![Part::new("segment1")?, Part::new("segment2")?]);
+        assert_eq!(parts.len(), 2);
+        assert!(!parts.is_empty());
+        assert_eq!(parts.get(1).map(Part::as_str), Some("segment2"));
+        assert_eq!(parts.get(2), None);
+        assert_eq!(parts[0].as_str(), "segment1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parts_len_empty() {
+        let parts = Parts::default();
+        assert_eq!(parts.len(), 0);
+        assert!(parts.is_empty());
+        assert_eq!(parts.get(0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parts_index_out_of_bounds() {
+        let parts = Parts::default();
+        let _ = &parts[0];
+    }
+
+    #[test]
+    fn test_parts_parse() -> anyhow::Result<()> {
+        let parts = Parts::parse("teamA/member1/widget")?;
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[2].as_str(), "widget");
+        assert_eq!(parts.to_string(), "teamA/member1/widget");
+
+        let parsed: Parts = "teamA".parse()?;
+        assert_eq!(parsed, Parts::new(vec![Part::new("teamA")?]));
+        assert!(Parts::parse("")?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parts_parse_rejects_empty_segments() {
+        for input in ["/teamA", "teamA/", "teamA//member1", "/"] {
+            assert!(
+                matches!(Parts::parse(input), Err(ArnError::ParseFailure("Part", _))),
+                "{input} should be rejected"
+            );
+        }
+        assert_eq!(
+            "teamA/mem:ber".parse::<Parts>(),
+            Err(ArnError::InvalidPartFormat)
+        );
+    }
+
+    #[test]
+    fn test_parts_contains() -> anyhow::Result<()> {
+        let parts = Parts::new(vec![Part::new("teamA")?, Part::new_encoded("ext/42")?]);
+        assert!(parts.contains("teamA"));
+        assert!(parts.contains("ext/42"));
+        assert!(!parts.contains("ext%2F42"));
+        assert!(!parts.contains("team"));
+        assert!(!Parts::default().contains("teamA"));
+        Ok(())
+    }
+
     #[test]
     fn test_parts_into_owned() -> anyhow::Result<()> {
         let parts = Parts::new(vec![Part::new("segment1")?, Part::new("segment2")?]);
@@ -1,10 +1,18 @@
 use crate::errors::ArnError;
-use derive_more::{AsRef, From, Into};
-use std::borrow::Cow;
-use std::fmt;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::fmt;
+use derive_more::{AsRef, Deref, Into};
+#[cfg(feature = "std")]
 use type_safe_id::{DynamicType, TypeSafeId};
 
-#[derive(AsRef, From, Into, Eq, Debug, PartialEq, Clone)]
+/// Represents the root of an Arn's resource path, such as `root_<id>`.
+///
+/// A root is made of ASCII letters, digits, `-`, and `_`, and cannot be empty. In particular it
+/// cannot contain `/`, which would make it indistinguishable from the parts that follow it, or
+/// the `:` component separator. A root derefs to `str`, exposing its text to string methods.
+#[derive(AsRef, Deref, Into, Eq, Debug, PartialEq, Clone, Hash, PartialOrd, Ord)]
+#[deref(forward)]
 pub struct Root<'a>(pub(crate) Cow<'a, str>);
 
 impl<'a> Root<'a> {
@@ -16,24 +24,90 @@ impl<'a> Root<'a> {
         Root(Cow::Owned(self.0.into_owned()))
     }
 
+    /// Creates a root holding `value` as is, e.g. `root_a` or `team-1`.
+    ///
+    /// Fails with `ArnError::ParseFailure` when `value` is empty and `ArnError::InvalidValue`
+    /// when it has a character outside the root charset. Use `Root::generate` to mint a unique
+    /// root id instead.
     pub fn new(value: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
         let value = value.into();
-        let value = if value.is_empty() {
-            let val = AKTON;
-            TypeSafeId::from_type_and_uuid(DynamicType::new(val)?, uuid::Uuid::now_v7())
-                .to_string()
-        } else {
-            TypeSafeId::from_type_and_uuid(DynamicType::new(&value)?, uuid::Uuid::now_v7())
-                .to_string()
-        };
-        Ok(Root(Cow::from(value)))
+        validate_root(&value)?;
+        Ok(Root(value))
     }
+
+    /// Mints a new root whose unique id has `prefix` as its type prefix, e.g.
+    /// `team_01h455vb4pex5vsknk084sn02q`.
+    ///
+    /// A TypeSafeId prefix is 1 to 63 lowercase ASCII letters and `_`, neither starting nor
+    /// ending with `_`, which is narrower than the root charset: `team-1`, `team1`, and `Team`
+    /// are valid roots but not valid prefixes. Fails with `ArnError::ParseFailure` when `prefix`
+    /// is empty, `ArnError::InvalidValue` when it is not a valid prefix, and, without the `std`
+    /// feature, `ArnError::IdGenerationFailure`. The minted root always matches the root charset.
+    pub fn generate(prefix: &str) -> Result<Root<'static>, ArnError> {
+        validate_prefix(prefix)?;
+        Ok(Root(Cow::Owned(generate_id(prefix)?)))
+    }
+}
+
+/// Returns true for the characters of the root charset: ASCII letters, digits, `-`, and `_`.
+pub(crate) fn is_root_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '_')
 }
 
+/// Checks the text of a root against the root charset, failing with `ArnError::ParseFailure`
+/// when it is empty and `ArnError::InvalidValue` when it has any other character.
+pub(crate) fn validate_root(value: &str) -> Result<(), ArnError> {
+    if value.is_empty() {
+        return Err(ArnError::ParseFailure(
+            "Root",
+            "cannot be empty".to_string(),
+        ));
+    }
+    if !value.chars().all(is_root_char) {
+        return Err(ArnError::InvalidValue("Root", value.to_string()));
+    }
+    Ok(())
+}
+
+/// Checks that `value` is accepted as a TypeSafeId prefix by `DynamicType::new`.
+fn validate_prefix(value: &str) -> Result<(), ArnError> {
+    if value.is_empty() {
+        return Err(ArnError::ParseFailure(
+            "Root",
+            "cannot be empty".to_string(),
+        ));
+    }
+    let valid = value.len() <= MAX_PREFIX_LENGTH
+        && value.bytes().all(|b| matches!(b, b'a'..=b'z' | b'_'))
+        && !value.starts_with('_')
+        && !value.ends_with('_');
+    if !valid {
+        return Err(ArnError::InvalidValue("Root", value.to_string()));
+    }
+    Ok(())
+}
+
+/// The longest type prefix a TypeSafeId accepts.
+const MAX_PREFIX_LENGTH: usize = 63;
+
+/// Mints a unique TypeSafeId with the given type prefix.
+#[cfg(feature = "std")]
+fn generate_id(prefix: &str) -> Result<String, ArnError> {
+    Ok(TypeSafeId::from_type_and_uuid(DynamicType::new(prefix)?, uuid::Uuid::now_v7()).to_string())
+}
+
+/// Minting unique ids needs a clock and randomness, which are only available with `std`.
+#[cfg(not(feature = "std"))]
+fn generate_id(_prefix: &str) -> Result<String, ArnError> {
+    Err(ArnError::IdGenerationFailure(
+        "requires the `std` feature".to_string(),
+    ))
+}
+
+#[cfg(feature = "std")]
 impl<'a> Default for Root<'a> {
     fn default() -> Self {
-        Root::new("").expect("Couldn't create default Akton ARN")
-        // Root(Cow::Borrowed(AKTON))
+        Root::generate(AKTON).expect("Couldn't create default Akton ARN")
     }
 }
 
@@ -43,9 +117,10 @@ impl<'a> fmt::Display for Root<'a> {
         write!(f, "{id}")
     }
 }
+#[cfg(feature = "std")]
 const AKTON: &str = "akton";
 
-impl<'a> std::str::FromStr for Root<'a> {
+impl<'a> core::str::FromStr for Root<'a> {
     type Err = ArnError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Root::new(s.to_string())
@@ -60,44 +135,144 @@ impl<'a> From<Root<'a>> for String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_root_derefs_to_str() -> anyhow::Result<()> {
+        let root: Root = "root_a".parse()?;
+        assert_eq!(root.find('_'), Some(4));
+        assert!(root.is_ascii());
+        Ok(())
+    }
 
     #[test]
     fn test_root_creation() {
         let root = Root::new("test").unwrap();
-        assert!(root.as_str().starts_with("test"));
+        assert_eq!(root.as_str(), "test");
+    }
+
+    #[test]
+    fn test_root_new_keeps_value_verbatim() -> anyhow::Result<()> {
+        for value in ["root_a", "team-1", "team1", "Team", "_team", "us-east-1"] {
+            assert_eq!(Root::new(value)?.as_str(), value);
+            assert_eq!(value.parse::<Root>()?.as_str(), value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_root_rejects_empty_value() {
+        assert_eq!(
+            Root::new(""),
+            Err(ArnError::ParseFailure(
+                "Root",
+                "cannot be empty".to_string()
+            ))
+        );
+        assert!(Root::generate("").is_err());
+    }
+
+    #[test]
+    fn test_root_rejects_separators() {
+        for value in ["team/a", "/team", "team:a"] {
+            assert_eq!(
+                Root::new(value),
+                Err(ArnError::InvalidValue("Root", value.to_string())),
+                "{value}"
+            );
+            assert!(validate_root(value).is_err(), "{value}");
+        }
+        assert!(Root::new("team a").is_err());
+    }
+
+    #[test]
+    fn test_root_generate_rejects_invalid_prefixes() {
+        let too_long = "a".repeat(MAX_PREFIX_LENGTH + 1);
+        for value in [
+            "team-1",
+            "team1",
+            "Team",
+            "_team",
+            "team_",
+            too_long.as_str(),
+        ] {
+            assert_eq!(
+                Root::generate(value),
+                Err(ArnError::InvalidValue("Root", value.to_string())),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_root_generate_valid_prefix() -> anyhow::Result<()> {
+        let root = Root::generate("team_a")?;
+        assert!(root.starts_with("team_a_"), "{root}");
+        assert!(validate_root(&root).is_ok());
+        assert!(Root::generate(&"a".repeat(MAX_PREFIX_LENGTH)).is_ok());
+        assert_ne!(Root::generate("test")?, Root::generate("test")?);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(not(feature = "std"))]
+    fn test_root_generate_requires_std() {
+        assert!(matches!(
+            Root::generate("team"),
+            Err(ArnError::IdGenerationFailure(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_root_charset() {
+        for value in [
+            "root",
+            "Team-1",
+            "us-east-1",
+            "root_01h455vb4pex5vsknk084sn02q",
+        ] {
+            assert!(validate_root(value).is_ok(), "{value}");
+        }
+        for value in ["ro ot", "r!@#", "ro.ot", "caf\u{e9}"] {
+            assert_eq!(
+                validate_root(value),
+                Err(ArnError::InvalidValue("Root", value.to_string())),
+                "{value}"
+            );
+        }
+        assert!(validate_root("").is_err());
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_root_default() {
         let root = Root::default();
-        assert!(root.as_str().starts_with("akton"));
+        assert!(root.as_str().starts_with("akton_"));
     }
 
     #[test]
     fn test_root_display() {
         let root = Root::new("example").unwrap();
-        assert!(format!("{}", root).starts_with("example"));
+        assert_eq!(format!("{}", root), "example");
     }
 
     #[test]
     fn test_root_from_str() {
         let root: Root = "test".parse().unwrap();
-        assert!(root.as_str().starts_with("test"));
+        assert_eq!(root.as_str(), "test");
     }
 
     #[test]
     fn test_root_equality() {
-        let root1 = Root::new("test");
-        let root2 = Root::new("test");
-        let root3 = Root::new("other");
-        assert_ne!(root1, root2);
-        assert_ne!(root1, root3);
+        assert_eq!(Root::new("test"), Root::new("test"));
+        assert_ne!(Root::new("test"), Root::new("other"));
     }
 
     #[test]
     fn test_root_into_string() {
         let root = Root::new("test").unwrap();
         let string: String = root.into();
-        assert!(string.starts_with("test"));
+        assert_eq!(string, "test");
     }
 }
@@ -0,0 +1,111 @@
+use crate::errors::ArnError;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::fmt;
+use derive_more::{AsRef, Into};
+
+/// The scheme used when none is configured, producing `arn:` prefixed names.
+pub(crate) const DEFAULT_SCHEME: &str = "arn";
+
+/// The scheme used by `QrnBuilder` and `QrnParser`.
+pub(crate) const QRN_SCHEME: &str = "qrn";
+
+/// Represents the scheme that prefixes an Arn, such as `arn` or `qrn`.
+///
+/// Schemes may only contain lowercase ASCII letters and digits.
+#[derive(AsRef, Into, Eq, Debug, PartialEq, Clone, Hash, PartialOrd, Ord)]
+pub struct Scheme<'a>(pub(crate) Cow<'a, str>);
+
+impl<'a> Scheme<'a> {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_owned(self) -> Scheme<'static> {
+        Scheme(Cow::Owned(self.0.into_owned()))
+    }
+
+    /// Converts to a `'static` scheme like `into_owned`, but borrows the `arn` and `qrn`
+    /// constants instead of allocating.
+    pub(crate) fn into_static(self) -> Scheme<'static> {
+        match self.as_str() {
+            DEFAULT_SCHEME => Scheme(Cow::Borrowed(DEFAULT_SCHEME)),
+            QRN_SCHEME => Scheme(Cow::Borrowed(QRN_SCHEME)),
+            _ => self.into_owned(),
+        }
+    }
+
+    pub fn new(value: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
+        let value = value.into();
+        if value.is_empty() {
+            Err(ArnError::ParseFailure(
+                "Scheme",
+                "cannot be empty".to_string(),
+            ))
+        } else if !value
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        {
+            Err(ArnError::InvalidValue("Scheme", value.into_owned()))
+        } else {
+            Ok(Scheme(value))
+        }
+    }
+}
+
+impl<'a> Default for Scheme<'a> {
+    fn default() -> Self {
+        Scheme(Cow::Borrowed(DEFAULT_SCHEME))
+    }
+}
+
+impl<'a> fmt::Display for Scheme<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'a> core::str::FromStr for Scheme<'a> {
+    type Err = ArnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scheme::new(s.to_string())
+    }
+}
+
+impl<'a> From<Scheme<'a>> for String {
+    fn from(scheme: Scheme<'a>) -> Self {
+        scheme.0.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheme_creation() -> anyhow::Result<()> {
+        let scheme = Scheme::new("qrn")?;
+        assert_eq!(scheme.as_str(), "qrn");
+        Ok(())
+    }
+
+    #[test]
+    fn test_scheme_default() {
+        assert_eq!(Scheme::default().as_str(), "arn");
+    }
+
+    #[test]
+    fn test_scheme_rejects_invalid_values() {
+        assert!(matches!(
+            Scheme::new(""),
+            Err(ArnError::ParseFailure("Scheme", _))
+        ));
+        for value in ["QRN", "q:rn", "q-rn"] {
+            assert_eq!(
+                Scheme::new(value),
+                Err(ArnError::InvalidValue("Scheme", value.to_string()))
+            );
+        }
+    }
+}
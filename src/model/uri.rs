@@ -0,0 +1,191 @@
+use crate::errors::ArnError;
+use crate::model::{Arn, Part};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+impl<'a> Arn<'a> {
+    /// Renders the Arn as a URI, e.g. `arn://akton/hr/acct/root/teamA/team1`, for URI-based
+    /// routing.
+    ///
+    /// The scheme becomes the URI scheme and every other component one path segment, in order;
+    /// an empty category becomes an empty segment. Each segment is percent-encoded byte by byte
+    /// except for the unreserved characters `A-Z a-z 0-9 - . _ ~`, so a literal `%` in a part is
+    /// written as `%25`. Parts are always separated by `/`, whatever the Arn's part separator.
+    pub fn to_uri(&self) -> String {
+        let mut uri = String::new();
+        uri.push_str(self.scheme());
+        uri.push_str(":/");
+        let components = [self.domain(), self.category(), self.account(), self.root()];
+        for segment in components
+            .into_iter()
+            .chain(self.parts.iter().map(Part::as_str))
+        {
+            uri.push('/');
+            encode_segment(&mut uri, segment);
+        }
+        uri
+    }
+
+    /// Parses the URI form written by `to_uri` back into an owned Arn with the default part
+    /// separator.
+    ///
+    /// The URI scheme is matched case-insensitively and becomes the Arn's scheme. Fails with
+    /// `ArnError::InvalidFormat` without a `://`, `ArnError::MissingSeparator` with fewer than
+    /// four path segments, `ArnError::InvalidCharacter` at a `%` not followed by two hex digits,
+    /// and `ArnError::InvalidUtf8`, at the start of the segment, when a decoded segment is not
+    /// UTF-8. The decoded components are validated as for any other Arn, with an invalid part
+    /// reported as `ArnError::InvalidPart`.
+    pub fn from_uri(uri: &str) -> Result<Arn<'static>, ArnError> {
+        let (scheme, path) = uri.split_once("://").ok_or(ArnError::InvalidFormat)?;
+        let mut offset = scheme.len() + 3;
+        let mut segments = Vec::new();
+        for segment in path.split('/') {
+            segments.push(decode_segment(segment, offset)?);
+            offset += segment.len() + 1;
+        }
+        if segments.len() < 4 {
+            return Err(ArnError::MissingSeparator {
+                position: uri.len(),
+            });
+        }
+        let parts = segments.split_off(4);
+        let mut components = segments.into_iter();
+        let mut next = || components.next().unwrap_or_default();
+        Arn::from_owned_components(
+            Some(scheme.to_ascii_lowercase()),
+            next(),
+            next(),
+            next(),
+            next(),
+            parts,
+        )
+    }
+}
+
+/// Appends `segment` to `uri`, percent-encoding every byte that is not unreserved.
+fn encode_segment(uri: &mut String, segment: &str) {
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            uri.push(char::from(byte));
+        } else {
+            // Writing to a String cannot fail.
+            let _ = write!(uri, "%{byte:02X}");
+        }
+    }
+}
+
+/// Decodes the percent-escapes in `segment`, which starts at byte `offset` of the URI.
+fn decode_segment(segment: &str, offset: usize) -> Result<String, ArnError> {
+    if !segment.contains('%') {
+        return Ok(String::from(segment));
+    }
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] != b'%' {
+            decoded.push(bytes[index]);
+            index += 1;
+            continue;
+        }
+        let escape = segment
+            .get(index + 1..index + 3)
+            .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            .ok_or(ArnError::InvalidCharacter {
+                found: '%',
+                position: offset + index,
+            })?;
+        decoded.push(escape);
+        index += 3;
+    }
+    String::from_utf8(decoded).map_err(|_| ArnError::InvalidUtf8 { position: offset })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArnParser;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_uri_round_trip() -> anyhow::Result<()> {
+        for (input, uri) in [
+            ("arn:akton:hr:acct:root", "arn://akton/hr/acct/root"),
+            (
+                "arn:akton:hr:acct:root/teamA/team1",
+                "arn://akton/hr/acct/root/teamA/team1",
+            ),
+            ("arn:akton::acct:root/teamA", "arn://akton//acct/root/teamA"),
+            (
+                "arn:akton:hr team:acct:root/a%2Fb/caf\u{e9}",
+                "arn://akton/hr%20team/acct/root/a%252Fb/caf%C3%A9",
+            ),
+        ] {
+            let arn = ArnParser::new(input).parse()?;
+            assert_eq!(arn.to_uri(), uri);
+            let restored = Arn::from_uri(uri)?;
+            assert_eq!(restored, arn);
+            assert_eq!(restored.to_string(), input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_uri_keeps_scheme() -> anyhow::Result<()> {
+        let qrn = crate::QrnParser::new("qrn:akton:hr:acct:root/teamA").parse()?;
+        assert_eq!(qrn.to_uri(), "qrn://akton/hr/acct/root/teamA");
+        assert_eq!(Arn::from_uri("QRN://akton/hr/acct/root/teamA")?, qrn);
+        Ok(())
+    }
+
+    #[test]
+    fn test_uri_uses_slashes_for_any_part_separator() -> anyhow::Result<()> {
+        let arn = ArnParser::new("arn:akton:hr:acct:root.teamA.team1")
+            .with_part_separator('.')?
+            .parse()?;
+        let uri = arn.to_uri();
+        assert_eq!(uri, "arn://akton/hr/acct/root/teamA/team1");
+        assert_eq!(
+            Arn::from_uri(&uri)?.to_string(),
+            "arn:akton:hr:acct:root/teamA/team1"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_uri_rejects_invalid_input() {
+        assert_eq!(
+            Arn::from_uri("arn:akton:hr:acct:root"),
+            Err(ArnError::InvalidFormat)
+        );
+        assert_eq!(
+            Arn::from_uri("arn://akton/hr/acct"),
+            Err(ArnError::MissingSeparator { position: 19 })
+        );
+        assert_eq!(
+            Arn::from_uri("arn://akton/hr/acct/root/a%2"),
+            Err(ArnError::InvalidCharacter {
+                found: '%',
+                position: 26
+            })
+        );
+        assert_eq!(
+            Arn::from_uri("arn://akton/hr/acct/root/%FF"),
+            Err(ArnError::InvalidUtf8 { position: 25 })
+        );
+        assert!(matches!(
+            Arn::from_uri("arn://akton/hr/acct/root/a%2Fb"),
+            Err(ArnError::InvalidPart { index: 0, .. })
+        ));
+        assert!(matches!(
+            Arn::from_uri("arn://akton/hr/acct/ro%3Aot"),
+            Err(ArnError::InvalidValue("Root", _))
+        ));
+        assert!(matches!(
+            Arn::from_uri("arn://akton/hr/acct/"),
+            Err(ArnError::ParseFailure("Root", _))
+        ));
+    }
+}
@@ -1,13 +1,35 @@
 use crate::errors::ArnError;
-use crate::model::{Account, Arn, Category, Domain, Part, Parts};
+use crate::model::{
+    check_part_separator, is_root_char, Account, Arn, Category, Domain, Part, Parts, Scheme,
+    DEFAULT_PART_SEPARATOR, DEFAULT_SCHEME, QRN_SCHEME,
+};
 use crate::Root;
-use std::borrow::Cow;
-use std::str::FromStr;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// A parser for decoding Arn strings into their constituent components.
 pub struct ArnParser<'a> {
     /// The Arn string to be parsed.
     arn: Cow<'a, str>,
+    /// The scheme the Arn string must start with.
+    scheme: Cow<'a, str>,
+    /// The maximum length in bytes of the Arn string, if any.
+    max_length: Option<usize>,
+    /// The maximum number of parts after the root, if any.
+    max_parts: Option<usize>,
+    /// Whether to normalize sloppy input before parsing instead of rejecting it.
+    lenient: bool,
+    /// The separator between the root and each part.
+    part_separator: char,
+    /// Whether to reject input containing non-ASCII characters.
+    ascii_only: bool,
+    /// Whether the parsed Arn keeps the exact input for `Display`.
+    keep_original: bool,
+    /// Whether to normalize the input to Unicode NFC before parsing.
+    #[cfg(feature = "unicode")]
+    nfc: bool,
 }
 
 impl<'a> ArnParser<'a> {
@@ -21,56 +43,604 @@ impl<'a> ArnParser<'a> {
     ///
     /// Returns an `ArnParser` instance initialized with the given Arn string.
     pub fn new(arn: impl Into<Cow<'a, str>>) -> Self {
-        Self { arn: arn.into() }
+        Self {
+            arn: arn.into(),
+            scheme: Cow::Borrowed(DEFAULT_SCHEME),
+            max_length: None,
+            max_parts: None,
+            lenient: false,
+            part_separator: DEFAULT_PART_SEPARATOR,
+            ascii_only: false,
+            keep_original: false,
+            #[cfg(feature = "unicode")]
+            nfc: false,
+        }
+    }
+
+    /// Switches the parser to lenient mode, which normalizes input before parsing it.
+    ///
+    /// Lenient mode trims surrounding whitespace, collapses runs of the part separator (`/` by
+    /// default) into one, and drops a trailing separator. In the default strict mode,
+    /// surrounding whitespace fails with `ArnError::UnexpectedWhitespace` and empty part
+    /// segments with `ArnError::EmptyPart`. Normalized input that had to be rewritten is parsed
+    /// into owned components.
+    pub fn lenient(mut self) -> Self {
+        self.lenient = true;
+        self
+    }
+
+    /// Keeps the exact input in the parsed Arn, so it displays and serializes byte for byte as
+    /// received, e.g. with an uppercase scheme or the whitespace a lenient parser accepted.
+    ///
+    /// The input is available from `Arn::original` until the Arn is modified, after which it
+    /// renders in canonical form again. Off by default.
+    pub fn keep_original(mut self) -> Self {
+        self.keep_original = true;
+        self
+    }
+
+    /// Rejects input containing any non-ASCII character with `ArnError::NonAscii` at the byte
+    /// offset of the first one, for downstream systems that only accept ASCII. Off by default.
+    pub fn ascii_only(mut self) -> Self {
+        self.ascii_only = true;
+        self
+    }
+
+    /// Normalizes the input to Unicode Normalization Form C before parsing, so visually
+    /// identical Arns, such as one with a precomposed `é` and one with `e` and a combining
+    /// accent, parse equal. Off by default.
+    ///
+    /// Input already in NFC is parsed without copying; otherwise the components are owned.
+    #[cfg(feature = "unicode")]
+    pub fn nfc(mut self) -> Self {
+        self.nfc = true;
+        self
+    }
+
+    /// Sets the scheme the Arn string must start with, such as `qrn`. Defaults to `arn`.
+    ///
+    /// # Arguments
+    ///
+    /// * `scheme` - The expected scheme, validated like `Scheme::new`; input with any other
+    ///   scheme fails with `ArnError::InvalidFormat`.
+    pub fn with_scheme(mut self, scheme: impl Into<Cow<'a, str>>) -> Result<Self, ArnError> {
+        self.scheme = Scheme::new(scheme)?.0;
+        Ok(self)
+    }
+
+    /// Sets the separator expected between the root and each part, such as `.`. Defaults to `/`.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator` - The part separator; `:`, the component separator, and characters that may
+    ///   appear in a root or part, such as `_`, `-`, alphanumerics, whitespace, and `%`, fail with
+    ///   `ArnError::InvalidValue`.
+    pub fn with_part_separator(mut self, separator: char) -> Result<Self, ArnError> {
+        self.part_separator = check_part_separator(separator)?;
+        Ok(self)
+    }
+
+    /// Limits the Arn string to at most `max` bytes. Parsing is unlimited by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum length in bytes; longer input fails with `ArnError::TooLong`.
+    pub fn with_max_length(mut self, max: usize) -> Self {
+        self.max_length = Some(max);
+        self
+    }
+
+    /// Limits the Arn to at most `max` parts after the root. Parsing is unlimited by default.
+    ///
+    /// The parts are counted before any is stored, so oversized input is rejected without
+    /// allocating for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum number of parts; more fail with `ArnError::TooManyParts`.
+    pub fn with_max_parts(mut self, max: usize) -> Self {
+        self.max_parts = Some(max);
+        self
     }
 
     /// Parses the Arn into its component parts and returns them as a structured result.
     /// Verifies correct Arn format and validates each part.
     ///
+    /// The scheme is matched case-insensitively, so `ARN:` and `Arn:` are accepted and stored as
+    /// `arn`. Every other component keeps its case. The root must be non-empty and made of the
+    /// root charset of `Root`; the first character outside it fails with
+    /// `ArnError::InvalidCharacter` at its offset.
+    ///
     /// # Returns
     ///
     /// Returns an `Arn` instance containing the parsed components.
     /// If parsing fails, returns an error message as a `String`.
+    ///
+    /// Components borrow from the input when the parser was given a `&str`, and are owned
+    /// otherwise. See `parse_borrowed` to parse a `&str` without constructing a parser.
+    ///
+    /// With the `tracing` feature enabled, each call runs inside an `arn_parse` debug span and
+    /// emits a debug event with the parsed components, or with the error on failure.
     pub fn parse(&self) -> Result<Arn<'a>, ArnError> {
-        let parts: Vec<&str> = self.arn.splitn(5, ':').collect();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("arn_parse", input = %self.arn).entered();
+        let result = match &self.arn {
+            Cow::Borrowed(arn) => self.parse_input(arn),
+            Cow::Owned(arn) => self.parse_input(arn).map(Arn::into_owned),
+        };
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(arn) => tracing::debug!(
+                scheme = arn.scheme(),
+                domain = arn.domain(),
+                category = arn.category(),
+                account = arn.account(),
+                root = arn.root(),
+                parts = %arn.parts(),
+                "parsed arn"
+            ),
+            Err(error) => tracing::debug!(%error, "failed to parse arn"),
+        }
+        result
+    }
 
-        if parts.len() != 5 || parts[0] != "arn" {
-            return Err(ArnError::InvalidFormat);
+    /// Parses `input` with this parser's options, normalizing it first if configured to.
+    fn parse_input<'b>(&self, input: &'b str) -> Result<Arn<'b>, ArnError> {
+        let mut arn = self.parse_prepared(input)?;
+        if self.keep_original {
+            arn.original = Some(Cow::Borrowed(input));
         }
+        Ok(arn)
+    }
 
-        let domain = Domain::from_str(parts[1])?;
-        let category = Category::from_str(parts[2])?;
-        let account = Account::from_str(parts[3])?;
+    /// Parses `input` after applying the lenient and NFC normalizations.
+    fn parse_prepared<'b>(&self, input: &'b str) -> Result<Arn<'b>, ArnError> {
+        let separator = self.part_separator;
+        match self.prepare(input) {
+            Cow::Borrowed(prepared) => {
+                self.check_input(prepared)?;
+                parse_components(prepared, &self.scheme, separator, self.max_parts)
+            }
+            Cow::Owned(prepared) => {
+                self.check_input(&prepared)?;
+                parse_components(&prepared, &self.scheme, separator, self.max_parts)
+                    .map(Arn::into_owned)
+            }
+        }
+    }
 
-        // Split the root and the path part
-        let root_path: Vec<&str> = parts[4].splitn(2, '/').collect();
-        let root_str = root_path[0];
-        let root = Root{ 0: root_str.to_string().into() };
+    /// Runs the length and ASCII checks this parser has enabled on the prepared input.
+    fn check_input(&self, input: &str) -> Result<(), ArnError> {
+        check_length(input.len(), self.max_length)?;
+        if self.ascii_only {
+            check_ascii(input)?;
+        }
+        Ok(())
+    }
 
-        // Continue with the path parts
-        let mut arn_parts = Vec::new();
-        if root_path.len() > 1 {
-            let path_parts: Vec<&str> = root_path[1].split('/').collect();
-            for part in path_parts.iter() {
-                arn_parts.push(Part::from_str(part)?);
-            }
+    /// Applies the lenient and NFC normalizations this parser has enabled, borrowing `input`
+    /// when neither changes it.
+    fn prepare<'b>(&self, input: &'b str) -> Cow<'b, str> {
+        let input = if self.lenient {
+            normalize_lenient(input, self.part_separator)
+        } else {
+            Cow::Borrowed(input)
+        };
+        // NFC never composes `:` or the ASCII part separators, so the components split the same.
+        #[cfg(feature = "unicode")]
+        if self.nfc {
+            return crate::model::nfc(input);
         }
+        input
+    }
+
+    /// Checks that the Arn string is valid without building an `Arn`.
+    ///
+    /// Runs exactly the checks `parse` does and returns the same error, but skips the parts
+    /// storage, so a strict parser of a borrowed string never allocates. In lenient or NFC mode the
+    /// input may still be copied while it is normalized.
+    pub fn validate(&self) -> Result<(), ArnError> {
+        let separator = self.part_separator;
+        let input = self.prepare(&self.arn);
+        self.check_input(&input)?;
+        validate_components(&input, &self.scheme, separator, self.max_parts)
+    }
+
+    /// Parses the Arn like `parse`, but reports every problem found instead of stopping at the
+    /// first.
+    ///
+    /// The input is normalized as `parse` normalizes it, then the length, the ASCII check when
+    /// enabled, surrounding whitespace, scheme, domain, account, root, part count, and each part
+    /// are all checked in one pass, in that order. These are the checks `validate` runs, so the
+    /// first error is always the one `parse` returns. Input without all five `:`-separated
+    /// components cannot be checked further, so it stops at `ArnError::MissingSeparator`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the parsed `Arn`, or a non-empty list of errors in the order they occur.
+    pub fn parse_all_errors(&self) -> Result<Arn<'a>, Vec<ArnError>> {
+        let input = self.prepare(&self.arn);
+        let mut errors = Vec::new();
+        errors.extend(check_length(input.len(), self.max_length).err());
+        if self.ascii_only {
+            errors.extend(check_ascii(&input).err());
+        }
+        check_components(
+            &input,
+            &self.scheme,
+            self.part_separator,
+            self.max_parts,
+            |error| {
+                errors.push(error);
+                true
+            },
+        );
+
+        if errors.is_empty() {
+            self.parse().map_err(|e| vec![e])
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Parses newline-separated input holding one Arn per line, such as the contents of a file.
+    ///
+    /// Lines are split on `\n` or `\r\n`, and blank or whitespace-only lines are skipped. Every
+    /// other line is parsed strictly with `parse_borrowed`, so the results are in line order and
+    /// a bad line yields its own error without affecting the rest.
+    pub fn parse_lines(input: &str) -> Vec<Result<Arn<'_>, ArnError>> {
+        input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(Self::parse_borrowed)
+            .collect()
+    }
+
+    /// Parses newline-separated input like `parse_lines`, spreading the lines across threads.
+    ///
+    /// The results are identical to `parse_lines` and stay in line order. Each `Arn` borrows
+    /// from `input`, which is shared read-only between the worker threads.
+    #[cfg(feature = "rayon")]
+    pub fn parse_lines_par(input: &str) -> Vec<Result<Arn<'_>, ArnError>> {
+        use rayon::prelude::*;
+
+        let lines: Vec<&str> = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+        lines.into_par_iter().map(Self::parse_borrowed).collect()
+    }
+
+    /// Parses an Arn whose components borrow directly from the input string, without allocating
+    /// a copy of any component. The scheme is stored as `'static`, so only a scheme other than
+    /// `arn` or `qrn` is copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The Arn string to be parsed. The returned `Arn` borrows from it.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `Arn` whose components are all `Cow::Borrowed` slices of `input`, apart from a
+    /// scheme with uppercase letters, which is lowercased into an owned copy.
+    pub fn parse_borrowed<'b>(input: &'b str) -> Result<Arn<'b>, ArnError> {
+        parse_components(input, DEFAULT_SCHEME, DEFAULT_PART_SEPARATOR, None)
+    }
+
+    /// Parses an Arn from a byte buffer, such as one read from the network, borrowing from it
+    /// like `parse_borrowed` without copying it into a `String` first.
+    ///
+    /// # Returns
+    ///
+    /// Returns `ArnError::InvalidUtf8` with the offset of the first invalid byte if `input` is
+    /// not UTF-8, or any error `parse_borrowed` reports.
+    pub fn parse_bytes(input: &[u8]) -> Result<Arn<'_>, ArnError> {
+        let input = core::str::from_utf8(input).map_err(|e| ArnError::InvalidUtf8 {
+            position: e.valid_up_to(),
+        })?;
+        Self::parse_borrowed(input)
+    }
+}
+
+/// Entry point for parsing Qrns, which starts an `ArnParser` expecting the `qrn` scheme.
+///
+/// The parser it returns is an ordinary `ArnParser`, and the result is an ordinary `Arn`.
+pub struct QrnParser;
+
+impl QrnParser {
+    /// Constructs a new `ArnParser` for a given Qrn string.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<'a>(qrn: impl Into<Cow<'a, str>>) -> ArnParser<'a> {
+        ArnParser {
+            scheme: Cow::Borrowed(QRN_SCHEME),
+            ..ArnParser::new(qrn)
+        }
+    }
+}
+
+/// The validated components of an Arn string, borrowed from it, with the parts still unsplit.
+struct Components<'b> {
+    scheme: &'b str,
+    domain: Domain<'b>,
+    category: Category<'b>,
+    account: Account<'b>,
+    root: &'b str,
+    parts: Option<&'b str>,
+}
+
+/// Splits `input` into its components and validates all but the parts, without allocating.
+///
+/// The parts are only counted against `max_parts`, so callers can reject oversized input before
+/// storing any of them.
+fn split_components<'b>(
+    input: &'b str,
+    scheme: &str,
+    separator: char,
+    max_parts: Option<usize>,
+) -> Result<Components<'b>, ArnError> {
+    reject_whitespace(input)?;
+    let mut components = input.splitn(5, ':');
+    let missing = || ArnError::MissingSeparator {
+        position: input.len(),
+    };
+
+    let parts_scheme = components.next().unwrap_or_default();
+    if !parts_scheme.eq_ignore_ascii_case(scheme) {
+        return Err(ArnError::InvalidFormat);
+    }
+    let domain = components.next().ok_or_else(missing)?;
+    let category = components.next().ok_or_else(missing)?;
+    let account = components.next().ok_or_else(missing)?;
+    let path = components.next().ok_or_else(missing)?;
+
+    let domain = Domain::new(domain)?;
+    let category = Category::new(category)?;
+    let account = Account::new(account)?;
+
+    // Split the root and the path part
+    let (root, parts) = match path.split_once(separator) {
+        Some((root, parts)) => (root, Some(parts)),
+        None => (path, None),
+    };
+    check_root(input, root)?;
+    if max_parts.is_some() {
+        let count = parts.map_or(0, |parts| parts.matches(separator).count() + 1);
+        check_parts_count(count, max_parts)?;
+    }
+
+    Ok(Components {
+        scheme: parts_scheme,
+        domain,
+        category,
+        account,
+        root,
+        parts,
+    })
+}
+
+/// Parses `input` into an Arn whose components borrow from it, requiring the given scheme and
+/// splitting the parts on `separator`.
+fn parse_components<'b>(
+    input: &'b str,
+    scheme: &str,
+    separator: char,
+    max_parts: Option<usize>,
+) -> Result<Arn<'b>, ArnError> {
+    let components = split_components(input, scheme, separator, max_parts)?;
+
+    // Continue with the path parts
+    let mut parts = Parts::default();
+    for part in components
+        .parts
+        .into_iter()
+        .flat_map(|p| p.split(separator))
+    {
+        parts = parts.add_part(check_part(input, part)?);
+    }
+
+    let mut arn = Arn::new(
+        components.domain,
+        components.category,
+        components.account,
+        Root(Cow::Borrowed(components.root)),
+        parts,
+    );
+    // Schemes are matched case-insensitively but always stored lowercase.
+    arn.scheme = Scheme(
+        if components.scheme.bytes().any(|b| b.is_ascii_uppercase()) {
+            Cow::Owned(components.scheme.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(components.scheme)
+        },
+    )
+    .into_static();
+    arn.part_separator = separator;
+    Ok(arn)
+}
+
+/// Runs every check `parse_components` does on `input` without building an Arn or allocating.
+fn validate_components(
+    input: &str,
+    scheme: &str,
+    separator: char,
+    max_parts: Option<usize>,
+) -> Result<(), ArnError> {
+    let mut first = Ok(());
+    check_components(input, scheme, separator, max_parts, |error| {
+        first = Err(error);
+        false
+    });
+    first
+}
+
+/// Runs every check `parse_components` does on `input`, in the same order, passing each failure
+/// to `report`. Stops when `report` returns false, or once a missing `:` leaves nothing to check.
+fn check_components(
+    input: &str,
+    scheme: &str,
+    separator: char,
+    max_parts: Option<usize>,
+    mut report: impl FnMut(ArnError) -> bool,
+) {
+    let mut check = |result: Result<(), ArnError>| match result {
+        Ok(()) => true,
+        Err(error) => report(error),
+    };
+    if !check(reject_whitespace(input)) {
+        return;
+    }
+    let mut components = input.splitn(5, ':');
+    let parts_scheme = components.next().unwrap_or_default();
+    if !parts_scheme.eq_ignore_ascii_case(scheme) && !check(Err(ArnError::InvalidFormat)) {
+        return;
+    }
+    let (Some(domain), Some(_category), Some(account), Some(path)) = (
+        components.next(),
+        components.next(),
+        components.next(),
+        components.next(),
+    ) else {
+        check(Err(ArnError::MissingSeparator {
+            position: input.len(),
+        }));
+        return;
+    };
+    if !check(Domain::new(domain).map(drop)) || !check(Account::new(account).map(drop)) {
+        return;
+    }
+
+    let (root, parts) = match path.split_once(separator) {
+        Some((root, parts)) => (root, Some(parts)),
+        None => (path, None),
+    };
+    let count = parts.map_or(0, |parts| parts.matches(separator).count() + 1);
+    if !check(check_root(input, root)) || !check(check_parts_count(count, max_parts)) {
+        return;
+    }
+    for part in parts.into_iter().flat_map(|p| p.split(separator)) {
+        if !check(check_part(input, part).map(drop)) {
+            return;
+        }
+    }
+}
+
+/// Rejects an Arn of `length` bytes when it exceeds the optional `max`.
+pub(crate) fn check_length(length: usize, max: Option<usize>) -> Result<(), ArnError> {
+    match max {
+        Some(max) if length > max => Err(ArnError::TooLong { length, max }),
+        _ => Ok(()),
+    }
+}
+
+/// Rejects `input` at the first byte that is not ASCII.
+pub(crate) fn check_ascii(input: &str) -> Result<(), ArnError> {
+    match input.bytes().position(|b| !b.is_ascii()) {
+        Some(position) => Err(ArnError::NonAscii { position }),
+        None => Ok(()),
+    }
+}
+
+/// Rejects an Arn with `count` parts when it exceeds the optional `max`.
+pub(crate) fn check_parts_count(count: usize, max: Option<usize>) -> Result<(), ArnError> {
+    match max {
+        Some(max) if count > max => Err(ArnError::TooManyParts { count, max }),
+        _ => Ok(()),
+    }
+}
+
+/// Trims surrounding whitespace, collapses runs of `separator`, and drops a trailing `separator`.
+///
+/// Borrows from `input` unless separators had to be rewritten.
+fn normalize_lenient(input: &str, separator: char) -> Cow<'_, str> {
+    let trimmed = input.trim();
+    let doubled = trimmed
+        .chars()
+        .zip(trimmed.chars().skip(1))
+        .any(|(a, b)| a == separator && b == separator);
+    if !doubled && !trimmed.ends_with(separator) {
+        return Cow::Borrowed(trimmed);
+    }
+    let mut normalized = String::with_capacity(trimmed.len());
+    for c in trimmed.chars() {
+        if !(c == separator && normalized.ends_with(separator)) {
+            normalized.push(c);
+        }
+    }
+    if normalized.ends_with(separator) {
+        normalized.pop();
+    }
+    Cow::Owned(normalized)
+}
+
+/// Rejects leading or trailing whitespace, reporting the byte offset of the first offending character.
+fn reject_whitespace(input: &str) -> Result<(), ArnError> {
+    if input.starts_with(char::is_whitespace) {
+        return Err(ArnError::UnexpectedWhitespace { position: 0 });
+    }
+    let trimmed = input.trim_end();
+    if trimmed.len() != input.len() {
+        return Err(ArnError::UnexpectedWhitespace {
+            position: trimmed.len(),
+        });
+    }
+    Ok(())
+}
 
-        // // Process the remaining parts after root
-        // let remaining_parts: Vec<&str> = parts[4].split("/").collect();
-        // for part in remaining_parts.iter() {
-        //     arn_parts.push(Part::from_str(part).map_err(|_| ParseError::InvalidPartFormat)?);
-        // }
+/// Validates a single part segment of `input`, reporting empty segments and separators by offset.
+fn check_part<'b>(input: &str, part: &'b str) -> Result<Part<'b>, ArnError> {
+    if part.is_empty() {
+        return Err(ArnError::EmptyPart {
+            position: offset_of(input, part),
+        });
+    }
+    reject_separator(input, part)?;
+    Part::new(part).map_err(|e| match e {
+        ArnError::UnexpectedWhitespace { position } => ArnError::UnexpectedWhitespace {
+            position: offset_of(input, part) + position,
+        },
+        e => e,
+    })
+}
 
-        let parts = Parts::new(arn_parts);
-        Ok(Arn::new(domain, category, account, root, parts))
+/// Checks the root of `input` against the root charset, reporting the first character outside
+/// it by its byte offset within `input`.
+fn check_root(input: &str, root: &str) -> Result<(), ArnError> {
+    if root.is_empty() {
+        return Err(ArnError::ParseFailure(
+            "Root",
+            "cannot be empty".to_string(),
+        ));
+    }
+    match root.char_indices().find(|&(_, c)| !is_root_char(c)) {
+        Some((index, found)) => Err(ArnError::InvalidCharacter {
+            found,
+            position: offset_of(input, root) + index,
+        }),
+        None => Ok(()),
     }
 }
 
+/// Rejects a `:` inside a component of `input`, reporting its byte offset within `input`.
+fn reject_separator(input: &str, component: &str) -> Result<(), ArnError> {
+    match component.find(':') {
+        Some(index) => Err(ArnError::InvalidCharacter {
+            found: ':',
+            position: offset_of(input, component) + index,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Returns the byte offset of `component`, a subslice of `input`, within `input`.
+fn offset_of(input: &str, component: &str) -> usize {
+    component.as_ptr() as usize - input.as_ptr() as usize
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use alloc::format;
 
     #[test]
     fn test_valid_arn_parsing() {
@@ -103,6 +673,574 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_borrowed_does_not_copy() -> anyhow::Result<()> {
+        let input = String::from("arn:custom:service:account123:root/resource/subresource");
+        let arn = ArnParser::parse_borrowed(&input)?;
+
+        assert!(matches!(arn.domain.0, Cow::Borrowed(_)));
+        assert!(matches!(arn.category.0, Cow::Borrowed(_)));
+        assert!(matches!(arn.account.0, Cow::Borrowed(_)));
+        assert!(matches!(arn.root.0, Cow::Borrowed(_)));
+        assert!(arn
+            .parts
+            .iter()
+            .all(|part| matches!(part.0, Cow::Borrowed(_))));
+        assert_eq!(arn.to_string(), input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_borrowed_invalid() {
+        assert_eq!(
+            ArnParser::parse_borrowed("invalid:arn:format"),
+            Err(ArnError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_invalid_character_position_in_part() {
+        let result = ArnParser::new("arn:domain:category:account:root/invalid:part").parse();
+        assert_eq!(
+            result,
+            Err(ArnError::InvalidCharacter {
+                found: ':',
+                position: 40
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_character_position_in_root() {
+        let result = ArnParser::new("arn:domain:category:account:ro:ot/part").parse();
+        assert_eq!(
+            result,
+            Err(ArnError::InvalidCharacter {
+                found: ':',
+                position: 30
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_separator_position() {
+        let input = "arn:domain:category:account";
+        assert_eq!(
+            ArnParser::new(input).parse(),
+            Err(ArnError::MissingSeparator { position: 27 })
+        );
+        assert_eq!(
+            ArnError::MissingSeparator { position: 27 }.to_string(),
+            "Missing ':' separator at position 27"
+        );
+    }
+
+    #[test]
+    fn test_arn_with_invalid_account() {
+        let result = ArnParser::new("arn:domain:category::root").parse();
+        assert!(matches!(result, Err(ArnError::ParseFailure("Account", _))));
+    }
+
+    #[test]
+    fn test_max_length_boundary() {
+        let arn_str = "arn:custom:service:account123:root/resource";
+        let max = arn_str.len();
+        assert!(ArnParser::new(arn_str).with_max_length(max).parse().is_ok());
+        assert_eq!(
+            ArnParser::new(arn_str).with_max_length(max - 1).parse(),
+            Err(ArnError::TooLong {
+                length: max,
+                max: max - 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_max_parts_boundary() {
+        let arn_str = "arn:custom:service:account123:root/a/b/c";
+        for parser in [
+            ArnParser::new(arn_str).with_max_parts(3),
+            ArnParser::new("arn:custom:service:account123:root").with_max_parts(0),
+        ] {
+            assert!(parser.parse().is_ok());
+            assert!(parser.validate().is_ok());
+        }
+        let parser = ArnParser::new(arn_str).with_max_parts(2);
+        let expected = || ArnError::TooManyParts { count: 3, max: 2 };
+        assert_eq!(parser.parse(), Err(expected()));
+        assert_eq!(parser.validate(), Err(expected()));
+        assert_eq!(parser.parse_all_errors(), Err(vec![expected()]));
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_nfc_parses_decomposed_input_equal() -> anyhow::Result<()> {
+        let precomposed = "arn:akton:hr:acct:root/caf\u{e9}";
+        let decomposed = "arn:akton:hr:acct:root/cafe\u{301}";
+        assert_ne!(
+            ArnParser::new(decomposed).parse()?,
+            ArnParser::new(precomposed).parse()?
+        );
+
+        let parsed = ArnParser::new(decomposed).nfc().parse()?;
+        assert_eq!(parsed, ArnParser::new(precomposed).parse()?);
+        assert!(ArnParser::new(decomposed).nfc().validate().is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ascii_only() -> anyhow::Result<()> {
+        let arn_str = "arn:akton:hr:acct:root/team\u{1F680}";
+        assert_eq!(
+            ArnParser::new(arn_str).parse()?.parts()[0].as_str(),
+            "team\u{1F680}"
+        );
+        assert_eq!(
+            ArnParser::new(arn_str).ascii_only().parse(),
+            Err(ArnError::NonAscii { position: 27 })
+        );
+        assert_eq!(
+            ArnParser::new(arn_str).ascii_only().validate(),
+            Err(ArnError::NonAscii { position: 27 })
+        );
+        assert!(ArnParser::new("arn:akton:hr:acct:root/team")
+            .ascii_only()
+            .parse()
+            .is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_parts_rejects_huge_input() {
+        let arn_str = format!("arn:custom:service:account123:root{}", "/a".repeat(10_000));
+        assert_eq!(
+            ArnParser::new(arn_str.as_str()).with_max_parts(64).parse(),
+            Err(ArnError::TooManyParts {
+                count: 10_000,
+                max: 64
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_encoded_parts() -> anyhow::Result<()> {
+        let mut arn = ArnParser::new("arn:akton:hr:acct:root").parse()?;
+        arn.parts = Parts::new(vec![
+            Part::new_encoded("ext/id:42")?,
+            Part::new_encoded("50%")?,
+        ]);
+
+        let text = arn.to_string();
+        assert_eq!(text, "arn:akton:hr:acct:root/ext%2Fid%3A42/50%25");
+
+        let parsed = ArnParser::new(text.as_str()).parse()?;
+        assert_eq!(parsed.parts.len(), 2);
+        assert_eq!(parsed.parts[0].as_decoded(), "ext/id:42");
+        assert_eq!(parsed.parts[1].as_decoded(), "50%");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_all_errors() {
+        let input = "arn:Bad_Domain:hr:acct/1:root/ok/bad:part//x";
+        let errors = ArnParser::new(input).parse_all_errors().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ArnError::InvalidValue("Domain", "Bad_Domain".to_string()),
+                ArnError::InvalidValue("Account", "acct/1".to_string()),
+                ArnError::InvalidCharacter {
+                    found: ':',
+                    position: 36,
+                },
+                ArnError::EmptyPart { position: 42 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_all_errors_reports_ascii_with_other_errors() {
+        let parser = ArnParser::new("arn:Akton:hr:acct:root/caf\u{e9}").ascii_only();
+        let errors = parser.parse_all_errors().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ArnError::NonAscii { position: 26 },
+                ArnError::InvalidValue("Domain", "Akton".to_string()),
+            ]
+        );
+        assert_eq!(parser.parse().unwrap_err(), errors[0]);
+        assert_eq!(parser.validate().unwrap_err(), errors[0]);
+    }
+
+    #[test]
+    fn test_parse_all_errors_structure() -> anyhow::Result<()> {
+        let errors = ArnParser::new("qrn:akton:hr")
+            .with_max_length(5)
+            .parse_all_errors()
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ArnError::TooLong { length: 12, max: 5 },
+                ArnError::InvalidFormat,
+                ArnError::MissingSeparator { position: 12 },
+            ]
+        );
+
+        let arn = ArnParser::new("arn:akton:hr:acct:root/teamA").parse_all_errors();
+        assert_eq!(
+            arn.map(|arn| arn.to_string()),
+            Ok("arn:akton:hr:acct:root/teamA".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lenient_normalizations() -> anyhow::Result<()> {
+        let expected = "arn:akton:hr:acct:root/teamA/member1";
+        for input in [
+            "  arn:akton:hr:acct:root/teamA/member1\n",
+            "arn:akton:hr:acct:root//teamA///member1",
+            "arn:akton:hr:acct:root/teamA/member1/",
+            " arn:akton:hr:acct:root//teamA/member1// ",
+        ] {
+            let arn = ArnParser::new(input).lenient().parse()?;
+            assert_eq!(arn.to_string(), expected, "{input:?}");
+        }
+
+        let arn = ArnParser::new("arn:akton:hr:acct:root/")
+            .lenient()
+            .parse()?;
+        assert!(arn.parts.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_rejections() {
+        for (input, error) in [
+            (
+                " arn:akton:hr:acct:root/teamA",
+                ArnError::UnexpectedWhitespace { position: 0 },
+            ),
+            (
+                "arn:akton:hr:acct:root/teamA\t",
+                ArnError::UnexpectedWhitespace { position: 28 },
+            ),
+            (
+                "arn:akton:hr:acct:root//teamA",
+                ArnError::EmptyPart { position: 23 },
+            ),
+            (
+                "arn:akton:hr:acct:root/teamA/",
+                ArnError::EmptyPart { position: 29 },
+            ),
+        ] {
+            assert_eq!(ArnParser::new(input).parse(), Err(error), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_empty_part_segments_rejected() {
+        for (input, position) in [
+            // `//` between two parts
+            ("arn:akton:hr:acct:root/teamA//teamB", 29),
+            // trailing slash after the last part
+            ("arn:akton:hr:acct:root/teamA/teamB/", 35),
+            // trailing slash directly after the root
+            ("arn:akton:hr:acct:root/", 23),
+            // leading slash in the parts region, right after the root separator
+            ("arn:akton:hr:acct:root//teamA", 23),
+        ] {
+            assert_eq!(
+                ArnParser::new(input).parse(),
+                Err(ArnError::EmptyPart { position }),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_with_part_separator() -> anyhow::Result<()> {
+        let input = "arn:akton:hr:acct:root.teamA.member1";
+        let arn = ArnParser::new(input).with_part_separator('.')?.parse()?;
+        assert_eq!(arn.root(), "root");
+        assert_eq!(arn.parts().len(), 2);
+        assert_eq!(arn.part_separator(), '.');
+        assert_eq!(arn.to_string(), input);
+
+        // With the default separator, the dots are left in the root, which rejects them.
+        assert_eq!(
+            ArnParser::new(input).parse(),
+            Err(ArnError::InvalidCharacter {
+                found: '.',
+                position: 22
+            })
+        );
+        assert_eq!(
+            ArnParser::new("arn:akton:hr:acct:root/a")
+                .with_part_separator('.')?
+                .parse(),
+            Err(ArnError::InvalidCharacter {
+                found: '/',
+                position: 22
+            })
+        );
+
+        assert_eq!(
+            ArnParser::new("arn:akton:hr:acct:root.teamA..member1")
+                .with_part_separator('.')?
+                .parse(),
+            Err(ArnError::EmptyPart { position: 29 })
+        );
+        let lenient = ArnParser::new("arn:akton:hr:acct:root.teamA..member1.")
+            .with_part_separator('.')?
+            .lenient()
+            .parse()?;
+        assert_eq!(lenient.to_string(), input);
+        assert!(ArnParser::new(input).with_part_separator(':').is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_roots() {
+        assert_eq!(
+            ArnParser::new("arn:akton:hr:acct:").parse(),
+            Err(ArnError::ParseFailure(
+                "Root",
+                "cannot be empty".to_string()
+            ))
+        );
+        assert!(ArnParser::new("arn:akton:hr:acct:/x").parse().is_err());
+        assert_eq!(
+            ArnParser::new("arn:akton:hr:acct:r!@#/x").parse(),
+            Err(ArnError::InvalidCharacter {
+                found: '!',
+                position: 19
+            })
+        );
+        assert_eq!(
+            ArnParser::new("arn:akton:hr:acct:ro ot/x")
+                .lenient()
+                .parse(),
+            Err(ArnError::InvalidCharacter {
+                found: ' ',
+                position: 20
+            })
+        );
+        assert!(ArnParser::new("arn:akton:hr:acct:ro ot/x")
+            .parse_all_errors()
+            .is_err());
+        assert!(ArnParser::new("arn:akton:hr:acct:").validate().is_err());
+        assert!(ArnParser::new("arn:akton:hr:acct:root-1_A/x")
+            .parse()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_matches_parse() {
+        let inputs = [
+            "arn:akton:hr:acct:root",
+            "arn:akton:hr:acct:root/teamA/member1",
+            "arn:akton::acct:root/teamA",
+            "arn:akton:hr:acct:root//teamA",
+            "arn:akton:hr:acct:root/teamA/",
+            "arn:akton:hr:acct:root/team:A",
+            "arn:akton:hr:acct:ro:ot",
+            "arn:akton:hr:acct",
+            "arn:Akton:hr:acct:root",
+            "arn:1akton:hr:acct:root",
+            "arn::hr:acct:root",
+            "arn:akton:hr::root",
+            "arn:akton:hr:ac/ct:root",
+            "qrn:akton:hr:acct:root",
+            " arn:akton:hr:acct:root",
+            "arn:akton:hr:acct:root/teamA ",
+            "arn:akton:hr:acct:root/ teamA",
+            "",
+            "arn",
+        ];
+        for input in inputs {
+            for parser in [
+                ArnParser::new(input),
+                ArnParser::new(input).lenient(),
+                ArnParser::new(input).with_max_length(30),
+                ArnParser::new(input).with_scheme(QRN_SCHEME).unwrap(),
+            ] {
+                assert_eq!(parser.validate(), parser.parse().map(drop), "{input:?}");
+            }
+            assert_eq!(Arn::is_valid(input), ArnParser::new(input).parse().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_parse_scheme_case_insensitively() -> anyhow::Result<()> {
+        let lower = ArnParser::new("arn:akton:HR:Acct:root/TeamA").parse()?;
+        for input in [
+            "ARN:akton:HR:Acct:root/TeamA",
+            "Arn:akton:HR:Acct:root/TeamA",
+        ] {
+            let arn = ArnParser::new(input).parse()?;
+            assert_eq!(arn, lower);
+            assert_eq!(arn.scheme(), "arn");
+            assert_eq!(arn.category(), "HR");
+            assert_eq!(arn.to_string(), "arn:akton:HR:Acct:root/TeamA");
+            assert_eq!(ArnParser::new(input).validate(), Ok(()));
+        }
+        let qrn = QrnParser::new("QRN:akton:hr:acct:root").parse()?;
+        assert_eq!(qrn.scheme(), "qrn");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_bytes() -> anyhow::Result<()> {
+        let arn = ArnParser::parse_bytes(b"arn:akton:hr:acct:root/teamA")?;
+        assert_eq!(arn, "arn:akton:hr:acct:root/teamA");
+        let unicode = ArnParser::parse_bytes("arn:akton:hr:acct:root/équipe".as_bytes())?;
+        assert_eq!(unicode.parts()[0].as_str(), "équipe");
+
+        assert_eq!(
+            ArnParser::parse_bytes(b"arn:akton:hr:\xffacct:root"),
+            Err(ArnError::InvalidUtf8 { position: 13 })
+        );
+        assert_eq!(
+            ArnParser::parse_bytes(b"arn:akton"),
+            Err(ArnError::MissingSeparator { position: 9 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_qrn_parser() -> anyhow::Result<()> {
+        let qrn = QrnParser::new("qrn:quasar:service:account123:root/resource").parse()?;
+        assert_eq!(qrn.scheme(), "qrn");
+        assert_eq!(
+            QrnParser::new("arn:quasar:service:account123:root").parse(),
+            Err(ArnError::InvalidFormat)
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_parse_emits_tracing_events() {
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing_subscriber::layer::{Context, SubscriberExt};
+        use tracing_subscriber::Layer;
+
+        #[derive(Clone, Default)]
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+
+        struct Fields(String);
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn core::fmt::Debug) {
+                self.0.push_str(&format!("{}={:?} ", field.name(), value));
+            }
+        }
+
+        impl<S: tracing::Subscriber> Layer<S> for Recorder {
+            fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+                let mut fields = Fields(String::new());
+                event.record(&mut fields);
+                self.0.lock().unwrap().push(fields.0);
+            }
+        }
+
+        let recorder = Recorder::default();
+        let subscriber = tracing_subscriber::registry().with(recorder.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            ArnParser::new("arn:akton:hr:acct:root/teamA")
+                .parse()
+                .unwrap();
+            ArnParser::new("arn:akton:hr").parse().unwrap_err();
+        });
+
+        let events = recorder.0.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(events[0].contains("message=parsed arn"), "{}", events[0]);
+        assert!(events[0].contains("domain=\"akton\""), "{}", events[0]);
+        assert!(events[0].contains("parts=teamA"), "{}", events[0]);
+        assert!(
+            events[1].contains("message=failed to parse arn"),
+            "{}",
+            events[1]
+        );
+        assert!(
+            events[1].contains("error=Missing ':' separator"),
+            "{}",
+            events[1]
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "arn:akton:hr:acct:root/teamA\n\
+                     \n\
+                     not-an-arn\r\n\
+                     arn:akton:hr:acct:root/teamB\n\
+                     \t\n\
+                     arn:Akton:hr:acct:root\n";
+        let results = ArnParser::parse_lines(input);
+        assert_eq!(results.len(), 4);
+        assert_eq!(
+            results[0].as_ref().map(ToString::to_string),
+            Ok("arn:akton:hr:acct:root/teamA".to_string())
+        );
+        assert_eq!(results[1], Err(ArnError::InvalidFormat));
+        assert_eq!(
+            results[2].as_ref().map(ToString::to_string),
+            Ok("arn:akton:hr:acct:root/teamB".to_string())
+        );
+        assert_eq!(
+            results[3],
+            Err(ArnError::InvalidValue("Domain", "Akton".to_string()))
+        );
+        assert!(ArnParser::parse_lines("\n\n").is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parse_lines_par_matches_sequential() {
+        let input: String = (0..50_000)
+            .map(|i| match i % 10 {
+                0 => "\n".to_string(),
+                7 => format!("arn:akton:hr:acct{i}:root/bad:part\n"),
+                _ => format!("arn:akton:hr:acct{i}:root/team{}/member{i}\n", i % 13),
+            })
+            .collect();
+        let parallel = ArnParser::parse_lines_par(&input);
+        assert_eq!(parallel.len(), 45_000);
+        assert_eq!(parallel, ArnParser::parse_lines(&input));
+    }
+
+    #[test]
+    fn test_parse_with_scheme() -> anyhow::Result<()> {
+        let input = "qrn:quasar:service:account123:root/resource";
+        let arn = ArnParser::new(input).with_scheme("qrn")?.parse()?;
+        assert_eq!(arn.scheme.as_str(), "qrn");
+        assert_eq!(arn.domain.as_str(), "quasar");
+        assert_eq!(arn.to_string(), input);
+
+        assert_eq!(ArnParser::new(input).parse(), Err(ArnError::InvalidFormat));
+        assert_eq!(
+            ArnParser::new("arn:quasar:service:account123:root")
+                .with_scheme("qrn")?
+                .parse(),
+            Err(ArnError::InvalidFormat)
+        );
+        for scheme in ["", "QRN", "q:rn"] {
+            assert!(
+                ArnParser::new(":akton:hr:acct:root")
+                    .with_scheme(scheme)
+                    .is_err(),
+                "{scheme:?}"
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_arn_parsing_with_owned_string() {
         let arn_str = String::from("arn:custom:service:account123:root/resource");
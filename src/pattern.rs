@@ -0,0 +1,431 @@
+use crate::errors::ArnError;
+use crate::model::{Arn, Part, Scheme};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A wildcard pattern such as `arn:akton:*:acct:root/*`, parsed once and matched against many Arns.
+///
+/// Each component and each part segment is matched independently, so a `*` matches any run of
+/// characters but never crosses a `:` or `/` boundary. Outside of `**`, a pattern matches only
+/// Arns with exactly as many part segments as the pattern has.
+///
+/// A part segment consisting solely of `**` matches zero or more whole part segments, so
+/// `arn:akton:hr:acct:root/**` matches `root`, `root/a`, and `root/a/b/c`. Segments following a
+/// `**` must match the trailing parts of the Arn: `root/**/member` matches `root/member` and
+/// `root/a/b/member`, but not `root/member/a`.
+///
+/// A component or part segment written as `{name}` matches any single value, like `*`, and
+/// binds it to `name` for `ArnPattern::captures`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArnPattern {
+    source: String,
+    scheme: String,
+    domain: Matcher,
+    category: Matcher,
+    account: Matcher,
+    root: Matcher,
+    parts: Vec<Matcher>,
+}
+
+impl ArnPattern {
+    /// Parses a pattern string into per-component matchers.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - A pattern in Arn form, where any component or part segment may contain `*`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `ArnError::InvalidFormat` if the pattern lacks the five `:`-separated components
+    /// or does not start with a literal scheme such as `arn` or `qrn`.
+    pub fn parse(pattern: &str) -> Result<Self, ArnError> {
+        let components: Vec<&str> = pattern.splitn(5, ':').collect();
+        if components.len() != 5 || Scheme::new(components[0]).is_err() {
+            return Err(ArnError::InvalidFormat);
+        }
+
+        let mut segments = components[4].split('/');
+        let root = Matcher::new(segments.next().unwrap_or_default());
+
+        Ok(ArnPattern {
+            source: pattern.to_string(),
+            scheme: components[0].to_string(),
+            domain: Matcher::new(components[1]),
+            category: Matcher::new(components[2]),
+            account: Matcher::new(components[3]),
+            root,
+            parts: segments.map(Matcher::new_part).collect(),
+        })
+    }
+
+    /// Checks whether the given Arn matches this pattern.
+    pub fn matches(&self, arn: &Arn) -> bool {
+        self.scheme == arn.scheme.as_str()
+            && self.domain.matches(arn.domain.as_str())
+            && self.category.matches(arn.category.as_str())
+            && self.account.matches(arn.account.as_str())
+            && self.root.matches(arn.root.as_str())
+            && match_parts(&self.parts, &arn.parts.0)
+    }
+
+    /// Matches the Arn like `matches`, returning the values bound to each `{name}` placeholder.
+    ///
+    /// For example, `arn:akton:hr:{account}:root/{team}` against `arn:akton:hr:acct:root/teamA`
+    /// captures `account` as `acct` and `team` as `teamA`. Returns `None` when the Arn does not
+    /// match. If a name appears more than once, the last binding wins.
+    #[cfg(feature = "std")]
+    pub fn captures(&self, arn: &Arn) -> Option<std::collections::HashMap<String, String>> {
+        if self.scheme != arn.scheme.as_str() {
+            return None;
+        }
+        let mut captured = Vec::new();
+        let components = [
+            (&self.domain, arn.domain.as_str()),
+            (&self.category, arn.category.as_str()),
+            (&self.account, arn.account.as_str()),
+            (&self.root, arn.root.as_str()),
+        ];
+        for (matcher, value) in components {
+            if !matcher.matches(value) {
+                return None;
+            }
+            matcher.capture(value, &mut captured);
+        }
+        if !capture_parts(&self.parts, &arn.parts.0, &mut captured) {
+            return None;
+        }
+        Some(
+            captured
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    /// Returns the leading segments an Arn must equal exactly to match: the scheme, followed by
+    /// each component and then each part segment, up to the first one that is not a literal.
+    pub(crate) fn literal_prefix(&self) -> impl Iterator<Item = &str> {
+        let components = [&self.domain, &self.category, &self.account, &self.root];
+        let literals =
+            components
+                .into_iter()
+                .chain(&self.parts)
+                .map_while(|matcher| match matcher {
+                    Matcher::Literal(literal) => Some(literal.as_str()),
+                    _ => None,
+                });
+        core::iter::once(self.scheme.as_str()).chain(literals)
+    }
+
+    /// Returns the pattern string this pattern was parsed from.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+}
+
+impl fmt::Display for ArnPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl core::str::FromStr for ArnPattern {
+    type Err = ArnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ArnPattern::parse(s)
+    }
+}
+
+/// A pre-parsed matcher for a single component or part segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Matcher {
+    /// A lone `*`, matching any value.
+    Any,
+    /// A value without wildcards, matched exactly.
+    Literal(String),
+    /// A value containing one or more `*` wildcards.
+    Wildcard(String),
+    /// A `**` part segment, matching zero or more whole part segments.
+    AnyDepth,
+    /// A `{name}` placeholder, matching any value and binding it to `name`.
+    Capture(String),
+}
+
+impl Matcher {
+    fn new(segment: &str) -> Self {
+        if segment == "*" {
+            Matcher::Any
+        } else if let Some(name) = capture_name(segment) {
+            Matcher::Capture(name.to_string())
+        } else if segment.contains('*') {
+            Matcher::Wildcard(segment.to_string())
+        } else {
+            Matcher::Literal(segment.to_string())
+        }
+    }
+
+    fn new_part(segment: &str) -> Self {
+        if segment == "**" {
+            Matcher::AnyDepth
+        } else {
+            Matcher::new(segment)
+        }
+    }
+
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            Matcher::Any | Matcher::AnyDepth | Matcher::Capture(_) => true,
+            Matcher::Literal(literal) => literal == value,
+            Matcher::Wildcard(pattern) => wildcard_match(pattern, value),
+        }
+    }
+
+    /// Records `value` under this matcher's name if it is a `{name}` placeholder.
+    #[cfg(feature = "std")]
+    fn capture<'m, 'v>(&'m self, value: &'v str, captured: &mut Vec<(&'m str, &'v str)>) {
+        if let Matcher::Capture(name) = self {
+            captured.push((name, value));
+        }
+    }
+}
+
+/// Returns the name inside a `{name}` placeholder segment, if `segment` is one.
+fn capture_name(segment: &str) -> Option<&str> {
+    segment
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+        .filter(|name| !name.is_empty() && !name.contains(['{', '}', '*']))
+}
+
+/// Matches part segments in order, letting each `**` absorb zero or more parts.
+fn match_parts(matchers: &[Matcher], parts: &[Part]) -> bool {
+    match matchers.split_first() {
+        None => parts.is_empty(),
+        Some((Matcher::AnyDepth, rest)) => {
+            (0..=parts.len()).any(|skip| match_parts(rest, &parts[skip..]))
+        }
+        Some((matcher, rest)) => match parts.split_first() {
+            Some((part, tail)) => matcher.matches(part.as_str()) && match_parts(rest, tail),
+            None => false,
+        },
+    }
+}
+
+/// Matches part segments like `match_parts`, recording placeholder bindings along the way.
+///
+/// Bindings made on a path that is later abandoned by `**` backtracking are rolled back.
+#[cfg(feature = "std")]
+fn capture_parts<'m, 'v>(
+    matchers: &'m [Matcher],
+    parts: &'v [Part],
+    captured: &mut Vec<(&'m str, &'v str)>,
+) -> bool {
+    let checkpoint = captured.len();
+    let matched = match matchers.split_first() {
+        None => parts.is_empty(),
+        Some((Matcher::AnyDepth, rest)) => {
+            (0..=parts.len()).any(|skip| capture_parts(rest, &parts[skip..], captured))
+        }
+        Some((matcher, rest)) => match parts.split_first() {
+            Some((part, tail)) if matcher.matches(part.as_str()) => {
+                matcher.capture(part.as_str(), captured);
+                capture_parts(rest, tail, captured)
+            }
+            _ => false,
+        },
+    };
+    if !matched {
+        captured.truncate(checkpoint);
+    }
+    matched
+}
+
+/// Returns true when `value` matches `pattern`, where `*` matches any run of characters.
+fn wildcard_match(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let value = value.as_bytes();
+    let (mut p, mut v) = (0, 0);
+    // Position of the last `*` seen in the pattern and the value index it was matched from
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while v < value.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, v));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == value[v] {
+            p += 1;
+            v += 1;
+        } else if let Some((star, matched)) = backtrack {
+            // Let the last `*` absorb one more character and retry
+            p = star + 1;
+            v = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&b| b == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArnParser;
+    use alloc::{format, vec};
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("*", "anything"));
+        assert!(wildcard_match("team*", "teamA"));
+        assert!(wildcard_match("*A", "teamA"));
+        assert!(wildcard_match("t*m*", "teamA"));
+        assert!(wildcard_match("exact", "exact"));
+        assert!(!wildcard_match("exact", "exactly"));
+        assert!(!wildcard_match("team*", "member"));
+        assert!(!wildcard_match("", "value"));
+    }
+
+    #[test]
+    fn test_pattern_parse() -> anyhow::Result<()> {
+        let pattern = ArnPattern::parse("arn:akton:*:acct:root/team*")?;
+        assert_eq!(pattern.domain, Matcher::Literal("akton".to_string()));
+        assert_eq!(pattern.category, Matcher::Any);
+        assert_eq!(pattern.parts, vec![Matcher::Wildcard("team*".to_string())]);
+        assert_eq!(pattern.to_string(), "arn:akton:*:acct:root/team*");
+        assert_eq!(
+            ArnPattern::parse("akton:*:acct"),
+            Err(ArnError::InvalidFormat)
+        );
+        assert_eq!(
+            ArnPattern::parse("*:akton:*:acct:root"),
+            Err(ArnError::InvalidFormat)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pattern_matches() -> anyhow::Result<()> {
+        let pattern: ArnPattern = "arn:akton:hr:*:root/team*".parse()?;
+        assert!(pattern.matches(&Arn::try_from("arn:akton:hr:acct:root/teamA")?));
+        assert!(pattern.matches(&Arn::try_from("arn:akton:hr:other:root/teamB")?));
+        assert!(!pattern.matches(&Arn::try_from("arn:akton:hr:acct:root/member")?));
+        assert!(!pattern.matches(&Arn::try_from("arn:akton:hr:acct:root/teamA/x")?));
+        assert!(!pattern.matches(&Arn::try_from("arn:akton:hr:acct:root")?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pattern_matches_scheme() -> anyhow::Result<()> {
+        let pattern = ArnPattern::parse("qrn:akton:hr:acct:root/*")?;
+        let qrn = ArnParser::new("qrn:akton:hr:acct:root/teamA")
+            .with_scheme("qrn")?
+            .parse()?;
+        assert!(pattern.matches(&qrn));
+        assert!(!pattern.matches(&Arn::try_from("arn:akton:hr:acct:root/teamA")?));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_pattern_captures() -> anyhow::Result<()> {
+        let pattern = ArnPattern::parse("arn:akton:hr:{account}:root/{team}")?;
+        let captures = pattern
+            .captures(&Arn::try_from("arn:akton:hr:acct:root/teamA")?)
+            .unwrap();
+        assert_eq!(captures.len(), 2);
+        assert_eq!(captures["account"], "acct");
+        assert_eq!(captures["team"], "teamA");
+
+        assert!(pattern.matches(&Arn::try_from("arn:akton:hr:other:root/teamB")?));
+        assert_eq!(
+            pattern.captures(&Arn::try_from("arn:akton:it:acct:root/teamA")?),
+            None
+        );
+        assert_eq!(
+            pattern.captures(&Arn::try_from("arn:akton:hr:acct:root/teamA/x")?),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_pattern_captures_with_any_depth() -> anyhow::Result<()> {
+        let pattern = ArnPattern::parse("arn:{domain}:hr:acct:{root}/**/{leaf}")?;
+        let captures = pattern
+            .captures(&Arn::try_from("arn:akton:hr:acct:root/a/b/widget")?)
+            .unwrap();
+        assert_eq!(captures["domain"], "akton");
+        assert_eq!(captures["root"], "root");
+        assert_eq!(captures["leaf"], "widget");
+
+        let pattern = ArnPattern::parse("arn:akton:hr:acct:root/{team}/**/member*")?;
+        let captures = pattern
+            .captures(&Arn::try_from("arn:akton:hr:acct:root/teamA/b/member1")?)
+            .unwrap();
+        assert_eq!(captures.len(), 1);
+        assert_eq!(captures["team"], "teamA");
+        Ok(())
+    }
+
+    #[test]
+    fn test_pattern_any_depth() -> anyhow::Result<()> {
+        let pattern = ArnPattern::parse("arn:akton:hr:acct:root/**")?;
+        for arn in [
+            "arn:akton:hr:acct:root",
+            "arn:akton:hr:acct:root/a",
+            "arn:akton:hr:acct:root/a/b/c",
+        ] {
+            assert!(pattern.matches(&Arn::try_from(arn)?), "{arn} should match");
+        }
+        assert!(!pattern.matches(&Arn::try_from("arn:akton:hr:acct:other/a")?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pattern_single_vs_any_depth() -> anyhow::Result<()> {
+        let single = ArnPattern::parse("arn:akton:hr:acct:root/*")?;
+        let any_depth = ArnPattern::parse("arn:akton:hr:acct:root/**")?;
+        let depths = [
+            ("arn:akton:hr:acct:root", false, true),
+            ("arn:akton:hr:acct:root/a", true, true),
+            ("arn:akton:hr:acct:root/a/b", false, true),
+        ];
+        for (arn, single_matches, any_depth_matches) in depths {
+            let arn = Arn::try_from(arn)?;
+            assert_eq!(single.matches(&arn), single_matches, "{arn} with *");
+            assert_eq!(any_depth.matches(&arn), any_depth_matches, "{arn} with **");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_pattern_any_depth_followed_by_segments() -> anyhow::Result<()> {
+        let pattern = ArnPattern::parse("arn:akton:hr:acct:root/**/member*")?;
+        assert!(pattern.matches(&Arn::try_from("arn:akton:hr:acct:root/member1")?));
+        assert!(pattern.matches(&Arn::try_from("arn:akton:hr:acct:root/a/b/member2")?));
+        assert!(!pattern.matches(&Arn::try_from("arn:akton:hr:acct:root")?));
+        assert!(!pattern.matches(&Arn::try_from("arn:akton:hr:acct:root/member1/a")?));
+
+        let pattern = ArnPattern::parse("arn:akton:hr:acct:root/teamA/**/x")?;
+        assert!(pattern.matches(&Arn::try_from("arn:akton:hr:acct:root/teamA/x")?));
+        assert!(pattern.matches(&Arn::try_from("arn:akton:hr:acct:root/teamA/b/x")?));
+        assert!(!pattern.matches(&Arn::try_from("arn:akton:hr:acct:root/teamB/b/x")?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pattern_matches_many_arns() -> anyhow::Result<()> {
+        let pattern = ArnPattern::parse("arn:akton:hr:acct*:root/team*/member1")?;
+        let arns: Vec<Arn> = (0..10_000)
+            .map(|i| format!("arn:akton:hr:acct{}:root/team{}/member{}", i % 7, i, i % 3).parse())
+            .collect::<Result<_, _>>()?;
+
+        let matched = arns.iter().filter(|arn| pattern.matches(arn)).count();
+        assert_eq!(matched, (0..10_000).filter(|i| i % 3 == 1).count());
+        Ok(())
+    }
+}
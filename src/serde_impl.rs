@@ -0,0 +1,167 @@
+use crate::model::Arn;
+use crate::parser::ArnParser;
+use alloc::borrow::ToOwned;
+use core::fmt;
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Serializes an Arn as its canonical string form, e.g. `arn:domain:category:account:root/part`.
+///
+/// The string form does not record the part separator and is parsed back with the default `/`,
+/// so only Arns using `/` round-trip. Deserializing an Arn written with another separator fails,
+/// since the separator is left inside its root. An original input kept with
+/// `ArnParser::keep_original` is not written, as it may not parse strictly.
+impl Serialize for Arn<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&Canonical(self))
+    }
+}
+
+/// Displays an Arn in its canonical form, ignoring any kept original input.
+struct Canonical<'s, 'a>(&'s Arn<'a>);
+
+impl fmt::Display for Canonical<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_canonical(f)
+    }
+}
+
+/// Deserializes an Arn from its canonical string form by routing through `ArnParser::parse`.
+///
+/// Any valid scheme is accepted, so Arns using `qrn` or a scheme set at runtime round-trip.
+impl<'de, 'a> Deserialize<'de> for Arn<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ArnVisitor)
+    }
+}
+
+struct ArnVisitor;
+
+impl<'de> Visitor<'de> for ArnVisitor {
+    type Value = Arn<'static>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an Arn string such as `arn:domain:category:account:root/part`")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let parser = value
+            .split_once(':')
+            .and_then(|(scheme, _)| {
+                ArnParser::new(value.to_owned())
+                    .with_scheme(scheme.to_ascii_lowercase())
+                    .ok()
+            })
+            .unwrap_or_else(|| ArnParser::new(value.to_owned()));
+        parser.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Account, Arn, ArnBuilder, Category, Domain, Part, Root};
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Config<'a> {
+        name: String,
+        arn: Arn<'a>,
+    }
+
+    #[test]
+    fn test_serialize_arn_to_string() -> anyhow::Result<()> {
+        let arn = ArnBuilder::new()
+            .with::<Domain>("akton-internal")?
+            .with::<Category>("hr")?
+            .with::<Account>("company123")?
+            .with::<Root>("root")?
+            .with::<Part>("departmentA")?
+            .build()?;
+
+        let json = serde_json::to_string(&arn)?;
+        assert_eq!(json, format!("\"{}\"", arn));
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_serde_round_trip() -> anyhow::Result<()> {
+        let config = Config {
+            name: "team".to_string(),
+            arn: ArnBuilder::new()
+                .with::<Domain>("akton-internal")?
+                .with::<Category>("hr")?
+                .with::<Account>("company123")?
+                .with::<Root>("root")?
+                .with::<Part>("departmentA")?
+                .with::<Part>("team1")?
+                .build()?,
+        };
+
+        let json = serde_json::to_string(&config)?;
+        let restored: Config = serde_json::from_str(&json)?;
+        assert_eq!(restored, config);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arn_serde_round_trip_keeps_scheme() -> anyhow::Result<()> {
+        for arn in [
+            crate::QrnParser::new("qrn:akton:hr:acct:root/teamA").parse()?,
+            Arn::try_from("arn:akton:hr:acct:root/teamA")?.replace_scheme("urn")?,
+        ] {
+            let json = serde_json::to_string(&arn)?;
+            let restored: Arn = serde_json::from_str(&json)?;
+            assert_eq!(restored, arn);
+            assert_eq!(restored.scheme(), arn.scheme());
+        }
+        let upper: Arn = serde_json::from_str("\"QRN:akton:hr:acct:root\"")?;
+        assert_eq!(upper.scheme(), "qrn");
+        Ok(())
+    }
+
+    #[test]
+    fn test_serialize_ignores_kept_original() -> anyhow::Result<()> {
+        let arn = crate::ArnParser::new(" ARN:akton:hr:acct:root//a/ ")
+            .lenient()
+            .keep_original()
+            .parse()?;
+        let json = serde_json::to_string(&arn)?;
+        assert_eq!(json, "\"arn:akton:hr:acct:root/a\"");
+        let restored: Arn = serde_json::from_str(&json)?;
+        assert_eq!(restored, arn);
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_requires_default_part_separator() -> anyhow::Result<()> {
+        let arn = crate::ArnParser::new("arn:akton:hr:acct:root.a.b")
+            .with_part_separator('.')?
+            .parse()?;
+        let json = serde_json::to_string(&arn)?;
+        assert_eq!(json, "\"arn:akton:hr:acct:root.a.b\"");
+        assert!(serde_json::from_str::<Arn>(&json).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_invalid_arn() {
+        let result: Result<Arn, _> = serde_json::from_str("\"invalid-arn-format\"");
+        let err = result.unwrap_err();
+        assert!(err.to_string().starts_with("ARN has invalid format"));
+
+        let result: Result<Arn, _> = serde_json::from_str("\"invalid:arn:format\"");
+        let err = result.unwrap_err();
+        assert!(err.to_string().starts_with("Missing ':' separator"));
+    }
+}
@@ -0,0 +1,187 @@
+//! Serializes an Arn as a structured object instead of its canonical string, for use with
+//! `#[serde(with = "akton_arn::serde_struct")]`:
+//!
+//! ```json
+//! { "domain": "akton", "category": "hr", "account": "acct", "root": "root", "parts": ["teamA"] }
+//! ```
+//!
+//! A `scheme` field is written only for schemes other than `arn`, and defaults to `arn` when
+//! absent on the way in. Every field is validated as it is deserialized: the domain, account,
+//! and scheme like their constructors, the category may not contain `:`, the root must be within
+//! the root charset of `Root`, and each part is validated like `Part::new`, with failures
+//! reported as `ArnError::InvalidPart`.
+
+use crate::model::{Arn, Part, DEFAULT_SCHEME};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::de::{self, Deserializer, MapAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+
+const FIELDS: &[&str] = &["scheme", "domain", "category", "account", "root", "parts"];
+
+/// Serializes `arn` as a structured object.
+pub fn serialize<S>(arn: &Arn, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let custom_scheme = arn.scheme() != DEFAULT_SCHEME;
+    let mut state = serializer.serialize_struct("Arn", 5 + usize::from(custom_scheme))?;
+    if custom_scheme {
+        state.serialize_field("scheme", arn.scheme())?;
+    } else {
+        state.skip_field("scheme")?;
+    }
+    state.serialize_field("domain", arn.domain())?;
+    state.serialize_field("category", arn.category())?;
+    state.serialize_field("account", arn.account())?;
+    state.serialize_field("root", arn.root())?;
+    let parts: Vec<&str> = arn.parts().iter().map(Part::as_str).collect();
+    state.serialize_field("parts", &parts)?;
+    state.end()
+}
+
+/// Deserializes a structured object into an owned Arn, validating each field.
+pub fn deserialize<'de, 'a, D>(deserializer: D) -> Result<Arn<'a>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct("Arn", FIELDS, ArnStructVisitor)
+}
+
+struct ArnStructVisitor;
+
+impl<'de> Visitor<'de> for ArnStructVisitor {
+    type Value = Arn<'static>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an Arn object with domain, category, account, root, and parts fields")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut scheme: Option<String> = None;
+        let mut domain: Option<String> = None;
+        let mut category: Option<String> = None;
+        let mut account: Option<String> = None;
+        let mut root: Option<String> = None;
+        let mut parts: Option<Vec<String>> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            let (field, slot) = match key.as_str() {
+                "scheme" => ("scheme", &mut scheme),
+                "domain" => ("domain", &mut domain),
+                "category" => ("category", &mut category),
+                "account" => ("account", &mut account),
+                "root" => ("root", &mut root),
+                "parts" => {
+                    if parts.is_some() {
+                        return Err(de::Error::duplicate_field("parts"));
+                    }
+                    parts = Some(map.next_value()?);
+                    continue;
+                }
+                other => return Err(de::Error::unknown_field(other, FIELDS)),
+            };
+            if slot.is_some() {
+                return Err(de::Error::duplicate_field(field));
+            }
+            *slot = Some(map.next_value()?);
+        }
+
+        let domain = domain.ok_or_else(|| de::Error::missing_field("domain"))?;
+        let category = category.ok_or_else(|| de::Error::missing_field("category"))?;
+        let account = account.ok_or_else(|| de::Error::missing_field("account"))?;
+        let root = root.ok_or_else(|| de::Error::missing_field("root"))?;
+        Arn::from_owned_components(
+            scheme,
+            domain,
+            category,
+            account,
+            root,
+            parts.unwrap_or_default(),
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Arn, ArnParser};
+    use alloc::string::ToString;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Resource<'a> {
+        #[serde(with = "crate::serde_struct")]
+        arn: Arn<'a>,
+    }
+
+    #[test]
+    fn test_struct_round_trip() -> anyhow::Result<()> {
+        let resource = Resource {
+            arn: ArnParser::new("arn:akton:hr:acct:root/teamA/team1").parse()?,
+        };
+        let json = serde_json::to_string(&resource)?;
+        assert_eq!(
+            json,
+            r#"{"arn":{"domain":"akton","category":"hr","account":"acct","root":"root","parts":["teamA","team1"]}}"#
+        );
+        let restored: Resource = serde_json::from_str(&json)?;
+        assert_eq!(restored, resource);
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_round_trip_keeps_scheme() -> anyhow::Result<()> {
+        let resource = Resource {
+            arn: crate::QrnParser::new("qrn:akton:hr:acct:root").parse()?,
+        };
+        let json = serde_json::to_string(&resource)?;
+        assert!(json.contains(r#""scheme":"qrn""#), "{json}");
+        assert!(json.contains(r#""parts":[]"#), "{json}");
+        let restored: Resource = serde_json::from_str(&json)?;
+        assert_eq!(restored, resource);
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_validation_failures() {
+        let cases = [
+            (
+                r#"{"arn":{"domain":"Akton","category":"hr","account":"acct","root":"root"}}"#,
+                "Domain has invalid value: Akton",
+            ),
+            (
+                r#"{"arn":{"domain":"akton","category":"hr","account":"","root":"root","parts":[]}}"#,
+                "Failed to parse Account: cannot be empty",
+            ),
+            (
+                r#"{"arn":{"domain":"akton","category":"hr","account":"acct","root":"ro:ot"}}"#,
+                "Root has invalid value: ro:ot",
+            ),
+            (
+                r#"{"arn":{"domain":"akton","category":"hr","account":"acct","root":""}}"#,
+                "Failed to parse Root: cannot be empty",
+            ),
+            (
+                r#"{"arn":{"domain":"akton","category":"hr","account":"acct","root":"root","parts":["ok","a/b"]}}"#,
+                "Part 1 has invalid value 'a/b'",
+            ),
+            (
+                r#"{"arn":{"domain":"akton","category":"hr","account":"acct"}}"#,
+                "missing field `root`",
+            ),
+            (
+                r#"{"arn":{"domain":"akton","category":"hr","account":"acct","root":"root","extra":1}}"#,
+                "unknown field `extra`",
+            ),
+        ];
+        for (json, message) in cases {
+            let err = serde_json::from_str::<Resource>(json).unwrap_err();
+            assert!(err.to_string().starts_with(message), "{err}");
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{Account, Category, Domain, Part, Parts, Root};
-use std::borrow::Cow;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
 
 /// Represents a component of a Arn (Akton Resource Name) that ensures type safety and ordering.
 pub trait ArnComponent<'a> {
@@ -1,2 +1,4 @@
-mod arn_component;
-pub use arn_component::ArnComponent;
+mod arn_component;
+mod scheme_marker;
+pub use arn_component::ArnComponent;
+pub use scheme_marker::{ArnScheme, DynScheme, QrnScheme, SchemeBuilder, SchemeKind, SchemeMarker};
@@ -0,0 +1,109 @@
+use crate::builder::ArnBuilder;
+use crate::model::{Domain, Scheme};
+use core::fmt;
+
+/// A compile-time marker for the scheme an `ArnBuilder` builds, used as in
+/// `ArnBuilder::<QrnScheme>::new()` so the emitted prefix is fixed by the builder's type.
+///
+/// The Arn such a builder builds is an `Arn<'a, S>`, which stores no scheme at all and writes
+/// `SCHEME` as its prefix.
+pub trait SchemeMarker {
+    /// The scheme written before the first `:`, e.g. `arn`.
+    const SCHEME: &'static str;
+}
+
+/// How an `Arn` holds its scheme: in a runtime field for `DynScheme`, or only in its type for
+/// a `SchemeMarker`.
+///
+/// Implemented for `DynScheme` and every `SchemeMarker`.
+pub trait SchemeKind {
+    /// The value an Arn keeps for its scheme, `()` for a `SchemeMarker`.
+    type Value: Clone + fmt::Debug;
+
+    /// Returns the scheme held by `value`.
+    fn as_str(value: &Self::Value) -> &str;
+
+    /// Keeps `scheme` as this kind's value, dropping it for a `SchemeMarker`.
+    fn from_scheme(scheme: Scheme<'_>) -> Self::Value;
+}
+
+/// Marks a builder or Arn whose scheme is chosen at runtime, with `ArnBuilder::with_scheme` or
+/// by the parser, and is stored in the Arn. This is the default, starting from `arn`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DynScheme;
+
+/// Marks a builder producing `arn:` names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ArnScheme;
+
+/// Marks a builder producing `qrn:` names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct QrnScheme;
+
+impl SchemeMarker for ArnScheme {
+    const SCHEME: &'static str = crate::model::DEFAULT_SCHEME;
+}
+
+impl SchemeMarker for QrnScheme {
+    const SCHEME: &'static str = crate::model::QRN_SCHEME;
+}
+
+impl SchemeKind for DynScheme {
+    type Value = Scheme<'static>;
+
+    fn as_str(value: &Self::Value) -> &str {
+        value.as_str()
+    }
+
+    fn from_scheme(scheme: Scheme<'_>) -> Self::Value {
+        scheme.into_static()
+    }
+}
+
+impl<S: SchemeMarker> SchemeKind for S {
+    type Value = ();
+
+    fn as_str(_: &Self::Value) -> &str {
+        S::SCHEME
+    }
+
+    fn from_scheme(_: Scheme<'_>) -> Self::Value {}
+}
+
+/// Starts an `ArnBuilder` whose scheme is fixed by a `SchemeMarker`, written
+/// `ArnBuilder::<QrnScheme>::new()`.
+///
+/// The plain `ArnBuilder::new()` stays an inherent constructor for the default `DynScheme`; an
+/// inherent `new` generic over the marker would leave the marker of every plain call uninferred.
+/// This trait must be in scope, and is part of the prelude. `ArnBuilder::for_scheme` does the
+/// same without the import.
+///
+/// ```
+/// use akton_arn::prelude::*;
+/// use akton_arn::{QrnScheme, Root};
+///
+/// let arn = ArnBuilder::<QrnScheme>::new()
+///     .with::<Domain>("akton")?
+///     .with::<Category>("hr")?
+///     .with::<Account>("acct")?
+///     .with::<Root>("root")?
+///     .with::<Part>("teamA")?
+///     .build()?;
+/// assert_eq!(arn.to_string(), "qrn:akton:hr:acct:root/teamA");
+/// # Ok::<(), akton_arn::ArnError>(())
+/// ```
+pub trait SchemeBuilder<'a> {
+    /// The builder in its first state, ready for the `Domain`.
+    type Builder;
+
+    /// Creates a new builder whose scheme is the marker's.
+    fn new() -> Self::Builder;
+}
+
+impl<'a, S: SchemeMarker> SchemeBuilder<'a> for ArnBuilder<'a, S> {
+    type Builder = ArnBuilder<'a, Domain<'a>, S>;
+
+    fn new() -> Self::Builder {
+        ArnBuilder::for_scheme::<S>()
+    }
+}
@@ -0,0 +1,194 @@
+use crate::model::Arn;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+
+/// A tree of Arns nested by their shared hierarchy: domain, then category, account, root, and
+/// each part in turn.
+///
+/// Every node is one segment of that path, and an inserted Arn is stored at the node where its
+/// path ends, so an Arn and its descendants share a subtree. Children are kept in
+/// lexicographic order. The scheme is not part of the path, so Arns that differ only in their
+/// scheme share a node and the last one inserted is kept.
+///
+/// ```
+/// use akton_arn::{Arn, ArnTree};
+///
+/// let tree: ArnTree = ["arn:akton:hr:acct:root/teamA", "arn:akton:hr:acct:root/teamB"]
+///     .into_iter()
+///     .map(|arn| Arn::try_from(arn).unwrap())
+///     .collect();
+/// let root = tree.get(&["akton", "hr", "acct", "root"]).unwrap();
+/// let names: Vec<&str> = root.children().map(|child| child.name()).collect();
+/// assert_eq!(names, ["teamA", "teamB"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArnTree<'a> {
+    root: ArnTreeNode<'a>,
+    len: usize,
+}
+
+/// One segment of the hierarchy in an `ArnTree`, holding the Arn whose path ends here, if any.
+#[derive(Debug, Clone, Default)]
+pub struct ArnTreeNode<'a> {
+    name: String,
+    arn: Option<Arn<'a>>,
+    children: BTreeMap<String, ArnTreeNode<'a>>,
+}
+
+impl<'a> ArnTree<'a> {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `arn` at the end of its path, creating the intermediate nodes as needed.
+    ///
+    /// Returns the Arn previously stored at that node, if any.
+    pub fn insert(&mut self, arn: Arn<'a>) -> Option<Arn<'a>> {
+        let mut node = &mut self.root;
+        for segment in arn.segments() {
+            node = node
+                .children
+                .entry(segment.to_string())
+                .or_insert_with(|| ArnTreeNode {
+                    name: segment.to_string(),
+                    ..ArnTreeNode::default()
+                });
+        }
+        let previous = node.arn.replace(arn);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    /// Returns the number of Arns stored in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when the tree holds no Arns.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterates over the top-level nodes, one per domain, in order.
+    pub fn children(&self) -> impl Iterator<Item = &ArnTreeNode<'a>> {
+        self.root.children()
+    }
+
+    /// Returns the node at the end of `path`, given as segments starting with the domain, or
+    /// `None` if no inserted Arn passes through it.
+    pub fn get(&self, path: &[&str]) -> Option<&ArnTreeNode<'a>> {
+        path.iter()
+            .try_fold(&self.root, |node, segment| node.child(segment))
+    }
+}
+
+impl<'a> ArnTreeNode<'a> {
+    /// Returns the segment this node stands for, e.g. a domain or a part.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the Arn whose path ends at this node, if one was inserted.
+    pub fn arn(&self) -> Option<&Arn<'a>> {
+        self.arn.as_ref()
+    }
+
+    /// Iterates over the child nodes in lexicographic order of their names.
+    pub fn children(&self) -> impl Iterator<Item = &ArnTreeNode<'a>> {
+        self.children.values()
+    }
+
+    /// Returns the child named `name`, if any.
+    pub fn child(&self, name: &str) -> Option<&ArnTreeNode<'a>> {
+        self.children.get(name)
+    }
+
+    /// Returns true when the node has no children.
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl<'a> FromIterator<Arn<'a>> for ArnTree<'a> {
+    fn from_iter<T: IntoIterator<Item = Arn<'a>>>(iter: T) -> Self {
+        let mut tree = ArnTree::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<'a> Extend<Arn<'a>> for ArnTree<'a> {
+    fn extend<T: IntoIterator<Item = Arn<'a>>>(&mut self, iter: T) {
+        for arn in iter {
+            self.insert(arn);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn names<'t>(nodes: impl Iterator<Item = &'t ArnTreeNode<'t>>) -> Vec<&'t str> {
+        nodes.map(ArnTreeNode::name).collect()
+    }
+
+    #[test]
+    fn test_tree_nests_by_hierarchy() -> anyhow::Result<()> {
+        let tree: ArnTree = [
+            "arn:akton:hr:acct:root/teamB",
+            "arn:akton:hr:acct:root/teamA/member1",
+            "arn:akton:hr:acct:root/teamA",
+            "arn:akton:service:acct:root",
+            "arn:other:hr:acct2:root2/team",
+        ]
+        .into_iter()
+        .map(Arn::try_from)
+        .collect::<Result<_, _>>()?;
+        assert_eq!(tree.len(), 5);
+
+        assert_eq!(names(tree.children()), ["akton", "other"]);
+        let akton = tree.get(&["akton"]).expect("domain node");
+        assert_eq!(names(akton.children()), ["hr", "service"]);
+        assert!(akton.arn().is_none());
+
+        let root = tree
+            .get(&["akton", "hr", "acct", "root"])
+            .expect("root node");
+        assert!(root.arn().is_none());
+        assert_eq!(names(root.children()), ["teamA", "teamB"]);
+
+        let team_a = root.child("teamA").expect("teamA node");
+        assert_eq!(
+            team_a.arn().map(|arn| arn.to_string()).as_deref(),
+            Some("arn:akton:hr:acct:root/teamA")
+        );
+        assert_eq!(names(team_a.children()), ["member1"]);
+        assert!(team_a.child("member1").is_some_and(ArnTreeNode::is_leaf));
+
+        let service = tree
+            .get(&["akton", "service", "acct", "root"])
+            .expect("service root");
+        assert!(service.arn().is_some() && service.is_leaf());
+        assert!(tree.get(&["akton", "hr", "missing"]).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tree_insert_replaces_duplicates() -> anyhow::Result<()> {
+        let mut tree = ArnTree::new();
+        assert!(tree.is_empty());
+        assert!(tree
+            .insert(Arn::try_from("arn:akton:hr:acct:root")?)
+            .is_none());
+        assert!(tree
+            .insert(crate::QrnParser::new("qrn:akton:hr:acct:root").parse()?)
+            .is_some());
+        assert_eq!(tree.len(), 1);
+        Ok(())
+    }
+}
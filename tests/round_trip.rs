@@ -0,0 +1,65 @@
+//! Property tests asserting that every Arn built from valid components survives
+//! `to_string` followed by `ArnParser::parse` unchanged.
+
+use akton_arn::*;
+use proptest::prelude::*;
+
+/// Domains: a lowercase ASCII letter followed by lowercase letters, digits, and `-`.
+fn domain() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9-]{0,31}"
+}
+
+/// Categories: any text without the `:` separator, including empty and non-ASCII text.
+fn category() -> impl Strategy<Value = String> {
+    "[^:]{0,32}"
+}
+
+/// Accounts: any non-empty text without the `:` and `/` separators.
+fn account() -> impl Strategy<Value = String> {
+    "[^:/]{1,32}"
+}
+
+/// Root prefixes, from which the builder generates a unique root id.
+fn root() -> impl Strategy<Value = String> {
+    "[a-z]{1,20}"
+}
+
+/// Parts: any non-empty text without the `:` and `/` separators or surrounding whitespace.
+fn parts() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec(r"[^:/\s]([^:/]{0,30}[^:/\s])?", 0..8)
+}
+
+proptest! {
+    #[test]
+    fn built_arns_round_trip(
+        domain in domain(),
+        category in category(),
+        account in account(),
+        root in root(),
+        parts in parts(),
+    ) {
+        let arn = ArnBuilder::new()
+            .domain(domain)?
+            .category(category)?
+            .account(account)?
+            .root(root)?
+            .with_parts(parts)?
+            .build()?;
+        let text = arn.to_string();
+        let parsed = ArnParser::new(text.as_str()).parse();
+        prop_assert_eq!(parsed, Ok(arn), "{:?}", text);
+    }
+
+    #[test]
+    fn long_arns_round_trip(parts in prop::collection::vec("[a-zA-Z0-9._-]{1,64}", 64..128)) {
+        let arn = ArnBuilder::new()
+            .domain("akton")?
+            .category("hr")?
+            .account("acct")?
+            .root("root")?
+            .with_parts(parts)?
+            .build()?;
+        let parsed = ArnParser::new(arn.to_string()).parse();
+        prop_assert_eq!(parsed, Ok(arn));
+    }
+}
@@ -42,20 +42,62 @@ fn test_parser() -> anyhow::Result<()> {
 
     // Verify each component matches the expected value
     assert_eq!(
-        arn.domain.to_string(),
+        arn.domain(),
         "akton-internal",
         "Domain should be 'akton-internal'"
     );
-    assert_eq!(arn.category.to_string(), "hr", "Category should be 'hr'");
+    assert_eq!(arn.category(), "hr", "Category should be 'hr'");
     assert_eq!(
-        arn.account.to_string(),
+        arn.account(),
         "company123",
         "Account should be 'company123'"
     );
     assert_eq!(
-        arn.parts.to_string(),
+        arn.parts().to_string(),
         "departmentA/team1",
         "Parts should match expected values"
     );
     Ok(())
 }
+
+#[test]
+fn test_arn_macro_matches_builder() -> anyhow::Result<()> {
+    let from_macro = arn!("akton", "hr", "acct", "root", "teamA", "team1")?;
+    let from_builder = ArnBuilder::new()
+        .with::<Domain>("akton")?
+        .with::<Category>("hr")?
+        .with::<Account>("acct")?
+        .with::<Root>("root")?
+        .with::<Part>("teamA")?
+        .with::<Part>("team1")?
+        .build()?;
+    assert_eq!(from_macro, from_builder);
+    assert_eq!(from_macro.root(), "root");
+    Ok(())
+}
+
+#[test]
+fn test_arn_macro_without_parts_and_errors() -> anyhow::Result<()> {
+    let arn = arn!("akton", "hr", "acct", "root")?;
+    assert!(arn.parts().is_empty());
+    assert!(arn!("Bad Domain", "hr", "acct", "root").is_err());
+    assert!(arn!("akton", "hr", "acct", "root", "bad/part").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_components_only_set_through_validated_apis() {
+    assert!(Domain::new("Bad Domain").is_err());
+    assert!(Category::new("hr:payroll").is_err());
+    assert!(Account::new("acct:other").is_err());
+    assert!(Root::new("root/sub").is_err());
+    assert!(Part::new("team/a").is_err());
+    assert!("hr:payroll".parse::<Category>().is_err());
+    assert!("root/sub".parse::<Root>().is_err());
+
+    assert!(Arn::try_new("akton", "hr:payroll", "acct", "root", ["team"]).is_err());
+    assert!(ArnBuilder::new().with::<Domain>("Bad Domain").is_err());
+    assert!(Arn::try_from("arn:akton:hr:acct:root/team")
+        .and_then(|arn| arn.replace_root("root/sub"))
+        .is_err());
+}